@@ -1,18 +1,101 @@
+use std::rc::Rc;
 use std::sync::Arc;
 
-use web_sys::KeyboardEvent;
-use yew::{function_component, html, use_state, Callback};
+use web_sys::{Event, FocusEvent, HtmlSelectElement, KeyboardEvent};
+use yew::{function_component, html, use_state, Callback, TargetCast};
 
+use crate::constants::dom::{GAME_CANVAS_ID, HOLD_CANVAS_ID, NEXT_CANVAS_ID};
 use crate::game::manager::GameManager;
+use crate::game::{Event as GameEvent, MinoShape};
 use crate::js_bind::focus::focus;
+use crate::options::game_option::GameOption;
+use crate::util::lock_or_recover;
+use crate::wasm_bind::{hold_panel_size, next_panel_size};
 
 #[function_component(GameBox)]
 pub fn game_box() -> Html {
-    let game_manager = GameManager::new();
+    // 연습 모드로 출현시킬 미노 (None이면 전체)
+    let practice_mino = use_state(|| None::<i32>);
+
+    let game_option = GameOption::default();
+    // 캔버스 크기는 옵션을 따름 (1:2가 아닌 보드도 블럭은 정사각형으로 그려짐)
+    let board_width = game_option.board_width.to_string();
+    let board_height = game_option.board_height.to_string();
+    // 넥스트 캔버스 크기는 미리보기 개수를 따름
+    let (next_width, next_height) = next_panel_size(game_option.next_preview_count);
+    let (hold_width, hold_height) = hold_panel_size();
+
+    // 매니저는 처음 그릴 때 한번만 만듦. 다시 그릴 때마다 새로 만들면 돌고 있는 루프가 이전 게임을 붙잡고 남음
+    let game_manager = use_state(|| Rc::new(GameManager::with_option(GameOption::default())));
+    let game_manager = Rc::clone(&*game_manager);
     let game_info = Arc::clone(&game_manager.game_info);
 
     let start_disabled = use_state(|| false);
 
+    let onchange_practice = {
+        let practice_mino = practice_mino.clone();
+        let game_manager = Rc::clone(&game_manager);
+
+        Callback::from(move |event: Event| {
+            let value = event.target_unchecked_into::<HtmlSelectElement>().value();
+            let code = value.parse::<i32>().ok();
+
+            let mino_list = match code {
+                Some(code) => vec![MinoShape::from(code)],
+                None => MinoShape::all(),
+            };
+
+            {
+                let mut game_info = lock_or_recover(&game_manager.game_info);
+                game_info.set_mino_list(mino_list);
+                // 연습 모드에서는 자동 일시정지를 끔
+                game_info.auto_pause = code.is_none();
+            }
+
+            practice_mino.set(code);
+        })
+    };
+
+    // 다른 탭이나 창으로 포커스가 넘어가면 자동 일시정지
+    let onblur = {
+        let game_manager = Rc::clone(&game_manager);
+
+        Callback::from(move |_: FocusEvent| {
+            game_manager.on_focus_lost();
+        })
+    };
+
+    let onclick_resume = {
+        let game_manager = Rc::clone(&game_manager);
+
+        Callback::from(move |_| {
+            focus("gamebox");
+            game_manager.resume_game();
+        })
+    };
+
+    let onclick_restart = {
+        let game_manager = Rc::clone(&game_manager);
+
+        Callback::from(move |_| {
+            focus("gamebox");
+            game_manager.restart_game();
+        })
+    };
+
+    // 거울 모드 전환. 진행 중에도 바로 반영됨
+    let onclick_mirror = {
+        let game_manager = Rc::clone(&game_manager);
+
+        Callback::from(move |_| {
+            focus("gamebox");
+
+            let mut game_info = lock_or_recover(&game_manager.game_info);
+            let mirror = !game_info.mirror;
+            game_info.set_mirror(mirror);
+        })
+    };
+
     //let _start_disabled = start_disabled.clone();
     let onclick = {
         //let start_disabled = _start_disabled;
@@ -27,46 +110,52 @@ pub fn game_box() -> Html {
         })
     };
 
+    let _game_info = Arc::clone(&game_info);
     let onkeydown = Callback::from(move |event: KeyboardEvent| {
-        match event.key_code() {
-            37 => {
-                game_info.lock().unwrap().left_move();
-            } // left move
-            39 => {
-                game_info.lock().unwrap().right_move();
-            } // right move
-            38 => {} // up move
-            40 => {
-                game_info.lock().unwrap().soft_drop();
-            } // down move
-            90 => {
-                game_info.lock().unwrap().left_rotate();
-            } // z
-            88 => {
-                game_info.lock().unwrap().right_rotate();
-            } // x
-            65 => {
-                game_info.lock().unwrap().double_rotate();
-            } // a
-            32 => {
-                game_info.lock().unwrap().hard_drop();
-            } // spacebar
-            16 => {
-                game_info.lock().unwrap().hold();
-            } // shift
-            _ => {}
+        let mut game_info = lock_or_recover(&game_info);
+        let key_code = event.key_code();
+
+        if key_code == game_info.key_bindings.help {
+            if !event.repeat() {
+                game_info.toggle_help(instant::Instant::now());
+            }
+            return;
+        }
+
+        if key_code == game_info.key_bindings.pause {
+            if !event.repeat() {
+                game_info.toggle_pause(instant::Instant::now());
+            }
+            return;
+        }
+
+        match game_info.key_bindings.press_event(key_code) {
+            // 누르고 있는 동안의 반복 이동은 DAS/ARR과 소프트드랍 중력으로 직접 처리하므로 브라우저 키 반복은 무시
+            Some(GameEvent::LeftMove | GameEvent::RightMove | GameEvent::SoftDrop)
+                if event.repeat() => {}
+            Some(game_event) => game_info.queue_event(game_event),
+            None => {}
+        }
+    });
+
+    let game_info = _game_info;
+    let onkeyup = Callback::from(move |event: KeyboardEvent| {
+        let mut game_info = lock_or_recover(&game_info);
+
+        if let Some(game_event) = game_info.key_bindings.release_event(event.key_code()) {
+            game_info.queue_event(game_event);
         }
     });
 
     html! {
-        <div id="gamebox" tabindex="0" class="flex content-start" {onkeydown} onclick={Callback::from(|_| {
+        <div id="gamebox" tabindex="0" class="flex content-start" {onkeydown} {onkeyup} {onblur} onclick={Callback::from(|_| {
             log::info!("test");
             GameManager::empty_render();
         })}>
             <div class="flex flex-col m-5 justify-start">
                 <div class="mb-[150px]">
                     <p class="font-mono text-2xl text-center">{"Hold"}</p>
-                    <canvas id="hold-canvas" class="" width="120" height="120"></canvas>
+                    <canvas id={HOLD_CANVAS_ID} class="" width={hold_width.to_string()} height={hold_height.to_string()}></canvas>
                 </div>
 
                 <div class="flex flex-col justify-between mb-[80px]">
@@ -80,6 +169,14 @@ pub fn game_box() -> Html {
                         <div class="font-mono text-base	">{"Score"}</div>
                         <div id="score">{"0"}</div>
                     </div>
+                    <div class="flex flex-row justify-between">
+                        <div class="font-mono text-base	">{"Level"}</div>
+                        <div id="level">{"1"}</div>
+                    </div>
+                    <div class="flex flex-row justify-between">
+                        <div class="font-mono text-base	">{"Lines"}</div>
+                        <div id="lines">{"0"}</div>
+                    </div>
                     <div class="flex flex-row justify-between">
                         <div class="font-mono text-base	content-start">{"Quad"}</div>
                         <div id="quad">{"0"}</div>
@@ -88,18 +185,40 @@ pub fn game_box() -> Html {
                         <div class="font-mono text-base	">{"PC"}</div>
                         <div id="pc">{"0"}</div>
                     </div>
+                    <div class="flex flex-row justify-between">
+                        <div class="font-mono text-base	">{"Finesse"}</div>
+                        <div id="finesse">{"0"}</div>
+                    </div>
+                    <div class="flex flex-row justify-between">
+                        <div class="font-mono text-base	">{"Time"}</div>
+                        <div id="time">{"00:00.000"}</div>
+                    </div>
                 </div>
 
+                <select class="mb-[10px] font-mono text-base" onchange={onchange_practice}>
+                    <option value="all" selected={practice_mino.is_none()}>{"All"}</option>
+                    <option value="0">{"I"}</option>
+                    <option value="1">{"J"}</option>
+                    <option value="2">{"L"}</option>
+                    <option value="3">{"O"}</option>
+                    <option value="4">{"S"}</option>
+                    <option value="5">{"T"}</option>
+                    <option value="6">{"Z"}</option>
+                </select>
+
                 <button class="bg-blue-500 hover:bg-blue-700 text-white font-bold py-2 px-4 rounded-full" onclick={onclick} disabled={*start_disabled}>{"Start"}</button>
+                <button class="mt-[10px] bg-gray-500 hover:bg-gray-700 text-white font-bold py-2 px-4 rounded-full" onclick={onclick_restart}>{"Restart"}</button>
+                <button class="mt-[10px] bg-gray-500 hover:bg-gray-700 text-white font-bold py-2 px-4 rounded-full" onclick={onclick_mirror}>{"Mirror"}</button>
             </div>
 
-            <div class="my-5">
-                <canvas id="game-canvas" width="300" height="600"></canvas>
+            <div class="my-5 relative" onclick={onclick_resume}>
+                <canvas id={GAME_CANVAS_ID} width={board_width} height={board_height}></canvas>
+                <div id="pause" class="absolute inset-0 flex items-center justify-center font-mono text-2xl pointer-events-none">{" "}</div>
             </div>
 
             <div class="m-5">
                 <p class="font-mono text-2xl text-center">{"Next"}</p>
-                <canvas id="next-canvas" class="" width="120" height="520"></canvas>
+                <canvas id={NEXT_CANVAS_ID} class="" width={next_width.to_string()} height={next_height.to_string()}></canvas>
             </div>
 
             <audio autoplay={true} loop={true}>
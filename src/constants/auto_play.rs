@@ -0,0 +1,6 @@
+// 자동 플레이 봇이 자리를 고를 때 쓰는 가중치. 점수가 가장 높은 자리를 고름
+// 높이, 구멍, 울퉁불퉁함은 적을수록, 지운 줄은 많을수록 좋음
+pub const AGGREGATE_HEIGHT_WEIGHT: f64 = -0.510066;
+pub const LINES_CLEARED_WEIGHT: f64 = 0.760666;
+pub const HOLE_WEIGHT: f64 = -0.35663;
+pub const BUMPINESS_WEIGHT: f64 = -0.184483;
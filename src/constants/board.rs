@@ -0,0 +1,19 @@
+// 쌓인 블럭이 보이는 영역 최상단에서 이 행 수 이내로 올라오면 다음 미노 출현 미리보기를 표시
+pub const SPAWN_PEEK_ROW_COUNT: u32 = 4;
+
+// 이만큼 줄을 지울 때마다 레벨이 하나씩 오름
+pub const LINES_PER_LEVEL: u32 = 10;
+
+// 기록 방식 가방에서 기억해둘 최근 미노 개수와, 겹칠 때 다시 뽑는 최대 횟수
+pub const CLASSIC_HISTORY_SIZE: usize = 4;
+pub const CLASSIC_ROLL_COUNT: u32 = 4;
+
+// 넥스트/홀드 패널의 열 개수와 한 칸 크기(px)
+pub const PANEL_COLUMN_COUNT: u32 = 6;
+pub const PANEL_BLOCK_SIZE: u32 = 20;
+
+// 홀드 패널의 행 개수. 미노 하나와 위아래 빈 줄 1칸씩
+pub const HOLD_PANEL_ROW_COUNT: u32 = 6;
+
+// 보드 위쪽에 보이지 않게 추가되는 행 개수 (미노 출현 공간)
+pub const HIDDEN_ROW_COUNT: u32 = 4;
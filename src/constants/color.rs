@@ -1,5 +1,6 @@
 pub const BOARD_DEFAULT_COLOR: &str = "#f4f3f5";
 pub const BOARD_STROKE_DEFAULT_COLOR: &str = "#000000";
+pub const BOARD_GRID_DEFAULT_COLOR: &str = "#dcdcdc";
 
 pub const NEXT_DEFAULT_COLOR: &str = "#212121";
 pub const NEXT_STROKE_DEFAULT_COLOR: &str = "#000000";
@@ -8,3 +9,6 @@ pub const HOLD_DEFAULT_COLOR: &str = "#212121";
 pub const HOLD_STROKE_DEFAULT_COLOR: &str = "#000000";
 
 pub const GHOST_DEFAULT_COLOR: &str = "#d3d3d3";
+
+// 색각 이상 대응 모드에서 블럭 안에 그리는 글자 색상
+pub const GLYPH_COLOR: &str = "rgba(0, 0, 0, 0.6)";
@@ -0,0 +1,4 @@
+// 캔버스 엘리먼트 id. gamebox 컴포넌트 마크업과 렌더링 함수가 같은 값을 사용해야 함
+pub const GAME_CANVAS_ID: &str = "game-canvas"; // 게임 보드
+pub const NEXT_CANVAS_ID: &str = "next-canvas"; // 넥스트 패널
+pub const HOLD_CANVAS_ID: &str = "hold-canvas"; // 홀드 패널
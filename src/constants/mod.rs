@@ -1,3 +1,6 @@
+pub mod auto_play;
+pub mod board;
 pub mod character;
 pub mod color;
+pub mod dom;
 pub mod time;
@@ -1,2 +1,11 @@
 // 틱 타임체크용 기준 반복시간 (밀리초)
 pub const TICK_LOOP_INTERVAL: u32 = 100;
+
+// 레벨이 아무리 높아도 틱 간격은 이보다 짧아지지 않음 (밀리초). 중력 틱이 틱 루프보다 너무 잦아지지 않도록 제한
+pub const MIN_TICK_INTERVAL: u64 = 50;
+
+// 옵션으로 지정할 수 있는 DAS/ARR의 최대값 (밀리초)
+pub const MAX_INPUT_DELAY: u32 = 1000;
+
+// 줄 삭제 연출에서 지울 줄이 빈 칸과 번갈아 보이는 간격 (밀리초). 틱 루프 간격보다 짧으면 깜빡임이 보이지 않음
+pub const LINE_CLEAR_FLASH_INTERVAL: u64 = 100;
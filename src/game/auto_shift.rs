@@ -0,0 +1,30 @@
+// 좌우 이동 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShiftDirection {
+    Left,
+    Right,
+}
+
+// 좌우 이동키를 누르고 있는 동안의 DAS 충전 상태
+// 미노가 고정되고 새 미노가 나와도 유지되므로, 충전된 상태라면 새 미노도 바로 밀려남
+#[derive(Debug, Clone, Copy)]
+pub struct AutoShift {
+    pub direction: ShiftDirection,
+    pub held_time: u32,   // 키를 누른 뒤 경과시간 (밀리초)
+    pub repeat_time: u32, // 충전 이후 다음 반복 이동까지 쌓인 시간 (밀리초)
+}
+
+impl AutoShift {
+    pub fn new(direction: ShiftDirection) -> Self {
+        Self {
+            direction,
+            held_time: 0,
+            repeat_time: 0,
+        }
+    }
+
+    // DAS 충전 여부
+    pub fn is_charged(&self, das: u32) -> bool {
+        self.held_time >= das
+    }
+}
@@ -1,6 +1,8 @@
 // 테트리스 가방 타입
 #[derive(Debug)]
 pub enum BagType {
-    NoBag,    // 가방 없음
-    SevenBag, // 7가방
+    NoBag,       // 가방 없음
+    SevenBag,    // 7가방
+    FourteenBag, // 14가방 (미노 목록 두 벌을 한번에 섞음)
+    Classic,     // 최근 나온 미노와 겹치면 다시 뽑는 기록 방식 (TGM)
 }
@@ -0,0 +1,82 @@
+use crate::game::{MinoShapeCells, Point};
+
+// 보드 칸마다 블럭이 고정된 시점 (게임 로직 시간, 밀리초). 고정된 블럭이 사라지는 모드에서 칸별로 언제 안 보이게 될지 계산
+// 보드와 같은 크기로 두고, 줄 삭제나 쓰레기 줄처럼 보드의 행이 움직일 때 같이 옮김. 시점이 없는 칸은 사라지지 않음
+#[derive(Debug, Clone, Default)]
+pub struct CellTimes {
+    pub times: Vec<Vec<Option<u64>>>,
+}
+
+impl CellTimes {
+    pub fn new(column_count: u32, row_count: u32) -> Self {
+        Self {
+            times: vec![vec![None; column_count as usize]; row_count as usize],
+        }
+    }
+
+    // 보드에 고정한 미노의 칸마다 고정 시점 기록
+    pub fn stamp(&mut self, mino: &MinoShapeCells, position: Point, time: u64) {
+        for (mino_y, row) in mino.iter().enumerate() {
+            for (mino_x, cell) in row.iter().enumerate() {
+                if cell.is_empty() {
+                    continue;
+                }
+
+                let x = position.x + mino_x as i64;
+                let y = position.y + mino_y as i64;
+                if x < 0 || y < 0 {
+                    continue;
+                }
+
+                if let Some(time_cell) = self
+                    .times
+                    .get_mut(y as usize)
+                    .and_then(|time_row| time_row.get_mut(x as usize))
+                {
+                    *time_cell = Some(time);
+                }
+            }
+        }
+    }
+
+    // TetrisBoard::clear_lines와 같은 순서로 지운 줄을 빼고 맨 위에 빈 줄을 채움. rows는 위에서부터
+    pub fn clear_rows(&mut self, rows: &[usize]) {
+        for &y in rows {
+            if y < self.times.len() {
+                let column_count = self.times[y].len();
+                self.times.remove(y);
+                self.times.insert(0, vec![None; column_count]);
+            }
+        }
+    }
+
+    // TetrisBoard::add_garbage와 같이 lines줄 밀어올림. 올라온 쓰레기 줄은 사라지지 않음
+    pub fn push_up(&mut self, lines: u32, row_count: u32) {
+        let bottom = (row_count as usize).min(self.times.len());
+
+        for _ in 0..lines {
+            if bottom == 0 {
+                break;
+            }
+
+            let removed = self.times.remove(0);
+            self.times.insert(bottom - 1, vec![None; removed.len()]);
+        }
+    }
+
+    // now 시점에 해당 칸이 이미 사라졌는지 여부
+    pub fn is_faded(&self, x: usize, y: usize, now: u64, fade_delay: u64) -> bool {
+        match self.times.get(y).and_then(|time_row| time_row.get(x)) {
+            Some(Some(time)) => now >= time + fade_delay,
+            _ => false,
+        }
+    }
+
+    // from 이후부터 to까지 사이에 사라진 칸이 있는지 여부
+    pub fn any_faded_between(&self, from: u64, to: u64, fade_delay: u64) -> bool {
+        self.times.iter().flatten().flatten().any(|time| {
+            let faded_at = time + fade_delay;
+            from < faded_at && faded_at <= to
+        })
+    }
+}
@@ -1,5 +1,7 @@
+pub mod cell_times;
 pub mod tetris_board;
 pub mod tetris_cell;
 
+pub use cell_times::*;
 pub use tetris_board::*;
 pub use tetris_cell::*;
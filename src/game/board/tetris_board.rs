@@ -1,10 +1,12 @@
 #![allow(clippy::explicit_counter_loop)]
 
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 
-use crate::game::{MinoShapeCells, Point, TetrisCell};
+use crate::game::{occupied_points, MinoShapeCells, Point, TetrisCell, TetrisError};
+use crate::util::fnv1a_hash;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TetrisBoard {
     pub column_count: u32,     //테트리스 열 개수(가로 길이)
     pub row_count: u32,        //테트리스 행 개수(세로 길이)
@@ -31,21 +33,243 @@ impl TetrisBoard {
         column_count: u32,
         row_count: u32,
         hidden_row_count: u32,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, TetrisError> {
+        if column_count == 0 || !unfolded.len().is_multiple_of(column_count as usize) {
+            return Err(TetrisError::InvalidDimensions);
+        }
+
+        let cells = unfolded
+            .into_iter()
+            .map(TetrisCell::try_from)
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .chunks(column_count as usize)
+            .into_iter()
+            .map(|chunk| chunk.collect::<Vec<TetrisCell>>())
+            .collect();
+
+        Ok(Self {
             column_count,
             row_count,
             board_width,
             board_height,
             hidden_row_count,
-            cells: unfolded
-                .into_iter()
-                .map(|e| TetrisCell::try_from(e).unwrap())
-                .chunks(column_count as usize)
-                .into_iter()
-                .map(|chunk| chunk.collect::<Vec<TetrisCell>>())
-                .collect(),
+            cells,
+        })
+    }
+
+    // 보드 크기 정보와 칸을 한번에 담은 바이트열. 저장/복원할 때 크기와 칸이 어긋나지 않도록 함께 다룸
+    // 앞의 20바이트는 열 개수, 행 개수, 숨겨진 행 개수, 너비, 높이 (u32 little endian), 이후 한 칸당 셀 코드 1바이트
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let header = [
+            self.column_count,
+            self.row_count,
+            self.hidden_row_count,
+            self.board_width,
+            self.board_height,
+        ];
+
+        header
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .chain(self.unfold().into_iter().map(|code| code as u8))
+            .collect()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, TetrisError> {
+        const HEADER_LEN: usize = 4 * 5;
+
+        if bytes.len() < HEADER_LEN {
+            return Err(TetrisError::InvalidDimensions);
+        }
+
+        let (header, cells) = bytes.split_at(HEADER_LEN);
+        let header = header
+            .chunks(4)
+            .map(|chunk| u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect::<Vec<_>>();
+
+        let (column_count, row_count, hidden_row_count) = (header[0], header[1], header[2]);
+        let (board_width, board_height) = (header[3], header[4]);
+
+        // 로직에서 사용하는 행이 모두 들어있어야 함
+        if cells.len() < column_count as usize * row_count as usize {
+            return Err(TetrisError::InvalidDimensions);
+        }
+
+        Self::from_unfold(
+            cells.iter().map(|code| *code as i32).collect(),
+            board_width,
+            board_height,
+            column_count,
+            row_count,
+            hidden_row_count,
+        )
+    }
+
+    // 크기와 칸 전체로 만든 해시. 리플레이 결과가 원본과 같은 보드인지 비교할 때 사용
+    pub fn board_hash(&self) -> u64 {
+        fnv1a_hash(self.to_bytes())
+    }
+
+    // 한 줄에 한 행씩, 한 칸에 한 글자로 나타낸 보드 (TetrisCell::to_ascii). 숨겨진 행부터 위에서 아래 순서
+    pub fn to_ascii(&self) -> String {
+        self.cells
+            .iter()
+            .map(|row| row.iter().map(TetrisCell::to_ascii).collect::<String>())
+            .join("\n")
+    }
+
+    // to_ascii 형식의 글자로 보드를 만듦. 앞뒤 공백과 빈 줄은 무시하고, 모든 행의 칸 수가 같아야 함
+    // 숨겨진 행은 없고 화면 크기(px)는 0이므로, 게임 보드로 쓸 때는 GameInfo::load_board_ascii 사용
+    pub fn from_ascii(text: &str) -> Result<Self, TetrisError> {
+        let cells = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                line.chars()
+                    .map(TetrisCell::from_ascii)
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let column_count = cells.first().map(|row| row.len()).unwrap_or(0);
+        if column_count == 0 || cells.iter().any(|row| row.len() != column_count) {
+            return Err(TetrisError::InvalidDimensions);
         }
+
+        Ok(Self {
+            column_count: column_count as u32,
+            row_count: cells.len() as u32,
+            hidden_row_count: 0,
+            board_width: 0,
+            board_height: 0,
+            cells,
+        })
+    }
+
+    // 이전에 그린 보드(unfold 결과)와 비교해서 바뀐 칸 목록. 보이는 영역 기준 (x, y, 새 칸)
+    // 이전 보드가 없거나 크기가 다르면 보이는 칸 전체가 나오므로 전체를 다시 그릴 때와 같음
+    pub fn changed_visible_cells(&self, previous_cells: &[i32]) -> Vec<(usize, usize, TetrisCell)> {
+        let column_count = self.column_count as usize;
+        let hidden_row_count = self.hidden_row_count as usize;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .skip(hidden_row_count)
+            .flat_map(|(y, row)| {
+                row.iter().enumerate().filter_map(move |(x, cell)| {
+                    (previous_cells.get(y * column_count + x) != Some(&cell.into_code()))
+                        .then_some((x, y - hidden_row_count, *cell))
+                })
+            })
+            .collect()
+    }
+
+    // 숨겨진 행까지 포함해서 블럭이 하나도 없는지 여부 (퍼펙트 클리어 판정)
+    pub fn is_empty(&self) -> bool {
+        self.cells.iter().flatten().all(|cell| cell.is_empty())
+    }
+
+    // 보이는 영역 중 블럭이 차있는 칸의 비율 (0.0 ~ 1.0)
+    pub fn fill_ratio(&self) -> f64 {
+        let visible_rows = self
+            .cells
+            .iter()
+            .take(self.row_count as usize)
+            .skip(self.hidden_row_count as usize);
+
+        let mut cell_count = 0;
+        let mut filled_count = 0;
+
+        for row in visible_rows {
+            cell_count += row.len();
+            filled_count += row.iter().filter(|cell| !cell.is_empty()).count();
+        }
+
+        if cell_count == 0 {
+            return 0.0;
+        }
+
+        filled_count as f64 / cell_count as f64
+    }
+
+    // 바닥에서 쓰레기 줄을 lines개 밀어올림. hole_column 칸만 비워둠
+    // 맨 위로 밀려난 줄에 블럭이 있었다면 true (탑아웃)
+    pub fn add_garbage(&mut self, lines: u32, hole_column: usize) -> bool {
+        let bottom = (self.row_count as usize).min(self.cells.len());
+        let mut topped_out = false;
+
+        for _ in 0..lines {
+            if bottom == 0 {
+                break;
+            }
+
+            let removed = self.cells.remove(0);
+            topped_out |= removed.iter().any(|cell| !cell.is_empty());
+
+            let garbage_row = (0..self.column_count as usize)
+                .map(|x| {
+                    if x == hole_column {
+                        TetrisCell::Empty
+                    } else {
+                        TetrisCell::Garbage
+                    }
+                })
+                .collect();
+            self.cells.insert(bottom - 1, garbage_row);
+        }
+
+        topped_out
+    }
+
+    // 가득 찬 줄의 행 번호 목록 (위에서부터)
+    pub fn full_rows(&self) -> Vec<usize> {
+        let row_count = (self.row_count as usize).min(self.cells.len());
+
+        (0..row_count)
+            .filter(|&y| self.cells[y].iter().all(|cell| !cell.is_empty()))
+            .collect()
+    }
+
+    // 좌우를 뒤집음. 뒤집힌 S는 Z 모양이 되므로 Z 색으로 바꾸는 식으로 칸도 TetrisCell::mirrored로 바꿈
+    // 두 번 뒤집으면 원래대로 돌아옴
+    pub fn mirror(&mut self) {
+        for row in &mut self.cells {
+            row.reverse();
+
+            for cell in row.iter_mut() {
+                *cell = cell.mirrored();
+            }
+        }
+    }
+
+    // 가득 찬 줄을 지우고 그 위의 줄(숨겨진 행 포함)을 한칸씩 내림. 맨 위에는 빈 줄을 채움
+    // row_count 아래의 여분 행은 건드리지 않음. 지운 줄 수 반환
+    pub fn clear_lines(&mut self) -> u8 {
+        let row_count = (self.row_count as usize).min(self.cells.len());
+        let mut line = 0;
+
+        for y in 0..row_count {
+            if self.cells[y].iter().all(|cell| !cell.is_empty()) {
+                self.cells.remove(y);
+                self.cells
+                    .insert(0, vec![TetrisCell::Empty; self.column_count as usize]);
+                line += 1;
+            }
+        }
+
+        line
+    }
+
+    // 쓰레기 블럭이 하나라도 남아있는 줄 수
+    pub fn garbage_row_count(&self) -> u32 {
+        self.cells
+            .iter()
+            .filter(|row| row.contains(&TetrisCell::Garbage))
+            .count() as u32
     }
 
     pub fn write_current_mino(&mut self, mino: MinoShapeCells, position: Point) {
@@ -92,4 +316,185 @@ impl TetrisBoard {
             mino_x += 1;
         }
     }
+
+    // write_current_mino로 그려넣은 미노를 지움. 미노와 같은 블럭이 들어있는 칸만 비우고 다른 블럭은 그대로 둠
+    // 게임 진행중인 미노는 GameInfo가 따로 들고 있다가 그릴 때만 겹쳐 그리므로, 미노를 그려넣은 복사본 보드에서만 필요
+    pub fn clear_mino(&mut self, mino: MinoShapeCells, position: Point) {
+        for point in occupied_points(&mino, position) {
+            let mino_cell = mino[(point.y - position.y) as usize][(point.x - position.x) as usize];
+
+            if point.x < 0 || point.y < 0 {
+                continue;
+            }
+
+            if let Some(cell) = self
+                .cells
+                .get_mut(point.y as usize)
+                .and_then(|row| row.get_mut(point.x as usize))
+            {
+                if *cell == mino_cell {
+                    *cell = TetrisCell::Empty;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::MinoShape;
+
+    #[test]
+    fn finds_full_rows() {
+        let board = TetrisBoard::from_ascii("....\nIIII\nJ.JJ\nOOOO").unwrap();
+
+        assert_eq!(board.full_rows(), vec![1, 3]);
+    }
+
+    #[test]
+    fn clear_lines_drops_the_rows_above() {
+        let mut board = TetrisBoard::from_ascii("T...\nIIII\nJ.JJ\nOOOO").unwrap();
+
+        assert_eq!(board.clear_lines(), 2);
+        assert_eq!(board.to_ascii(), "....\n....\nT...\nJ.JJ");
+    }
+
+    #[test]
+    fn clear_lines_without_full_rows_keeps_the_board() {
+        let mut board = TetrisBoard::from_ascii("T...\nJ.JJ").unwrap();
+
+        assert_eq!(board.clear_lines(), 0);
+        assert_eq!(board.to_ascii(), "T...\nJ.JJ");
+    }
+
+    #[test]
+    fn writes_a_mino_onto_empty_cells() {
+        let mut board = TetrisBoard::from_ascii("....\n....\n....\nZ...").unwrap();
+
+        board.write_current_mino(MinoShape::T.cells, Point { x: 1, y: 2 });
+
+        assert_eq!(board.to_ascii(), "....\n....\n..T.\nZTTT");
+    }
+
+    #[test]
+    fn fill_ratio_of_empty_and_full_boards() {
+        let empty = TetrisBoard::from_ascii("....\n....").unwrap();
+        let full = TetrisBoard::from_ascii("IIII\nGGGG").unwrap();
+
+        assert_eq!(empty.fill_ratio(), 0.0);
+        assert_eq!(full.fill_ratio(), 1.0);
+    }
+
+    #[test]
+    fn fill_ratio_counts_only_visible_cells() {
+        let mut board = TetrisBoard::from_ascii("TTTT\nGG..\n.G..").unwrap();
+        assert_eq!(board.fill_ratio(), 7.0 / 12.0);
+
+        // 숨겨진 행은 세지 않음
+        board.hidden_row_count = 1;
+        assert_eq!(board.fill_ratio(), 3.0 / 8.0);
+    }
+
+    #[test]
+    fn serde_round_trip_keeps_the_cells() {
+        let board = TetrisBoard::from_ascii("..T.\nGGG.\nIJLO").unwrap();
+
+        let json = serde_json::to_string(&board).unwrap();
+        let restored: TetrisBoard = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.to_ascii(), board.to_ascii());
+        assert_eq!(restored.column_count, board.column_count);
+        assert_eq!(restored.row_count, board.row_count);
+    }
+
+    #[test]
+    fn diffed_redraw_touches_only_the_moved_cells() {
+        // 10x20 보드 (숨겨진 행 4개) 바닥에 블럭을 깔고 T를 한칸 옮겼을 때 다시 그릴 칸 수 비교
+        let mut board = TetrisBoard::from_ascii(&vec![".........."; 24].join("\n")).unwrap();
+        board.hidden_row_count = 4;
+        board.cells[23] = vec![TetrisCell::Garbage; 10];
+        board.write_current_mino(MinoShape::T.cells, Point { x: 3, y: 10 });
+        let previous = board.unfold();
+
+        let mut moved = board.clone();
+        moved.cells[10..12]
+            .iter_mut()
+            .for_each(|row| row.fill(TetrisCell::Empty));
+        moved.write_current_mino(MinoShape::T.cells, Point { x: 4, y: 10 });
+
+        let full = moved.changed_visible_cells(&[]);
+        let diffed = moved.changed_visible_cells(&previous);
+
+        assert_eq!(full.len(), 200);
+        assert_eq!(diffed.len(), 4);
+        assert!(diffed.iter().all(|&(_, y, _)| y == 6 || y == 7));
+        assert!(moved.changed_visible_cells(&moved.unfold()).is_empty());
+    }
+
+    #[test]
+    fn garbage_pushes_rows_up_with_a_hole() {
+        let mut board = TetrisBoard::from_ascii("....\n....\n....\n.T..").unwrap();
+
+        let topped_out = board.add_garbage(2, 2);
+
+        assert!(!topped_out);
+        assert_eq!(board.to_ascii(), "....\n.T..\nGG.G\nGG.G");
+    }
+
+    #[test]
+    fn garbage_tops_out_when_blocks_leave_the_ceiling() {
+        let mut board = TetrisBoard::from_ascii("....\n.T..\n....\n....").unwrap();
+
+        // 두번째 줄까지는 빈 줄만 밀려나고, 세번째 줄부터 블럭이 밀려남
+        assert!(!board.add_garbage(1, 0));
+        assert!(board.add_garbage(1, 0));
+    }
+
+    #[test]
+    fn mirror_swaps_the_mirrored_minos() {
+        let mut board = TetrisBoard::from_ascii(".SS.J\nSS..J\nT..JJ").unwrap();
+
+        board.mirror();
+
+        // S는 Z로, J는 L로 보임
+        assert_eq!(board.to_ascii(), "L.ZZ.\nL..ZZ\nLL..T");
+    }
+
+    #[test]
+    fn mirror_twice_restores_the_board() {
+        let text = ".SS.J\nSS..J\nTZLJO\nIIIIG";
+        let mut board = TetrisBoard::from_ascii(text).unwrap();
+
+        board.mirror();
+        assert_ne!(board.to_ascii(), text);
+        board.mirror();
+
+        assert_eq!(board.to_ascii(), text);
+    }
+
+    #[test]
+    fn clear_then_spawn_at_the_same_point_is_a_no_op() {
+        let mut board = TetrisBoard::from_ascii("..........\n..........\nGGG...GGGG").unwrap();
+        let position = Point { x: 3, y: 0 };
+        board.write_current_mino(MinoShape::T.cells, position);
+        let before = board.cells.clone();
+
+        board.clear_mino(MinoShape::T.cells, position);
+        assert_ne!(board.cells, before);
+        assert!(board.cells[0].iter().all(|cell| cell.is_empty()));
+
+        board.write_current_mino(MinoShape::T.cells, position);
+        assert_eq!(board.cells, before);
+    }
+
+    #[test]
+    fn clear_mino_keeps_locked_cells() {
+        // T의 윗칸 자리에 다른 블럭이 고정되어 있음
+        let mut board = TetrisBoard::from_ascii("....G.....\n...TTT....\nGGG...GGGG").unwrap();
+
+        board.clear_mino(MinoShape::T.cells, Point { x: 3, y: 0 });
+
+        assert_eq!(board.to_ascii(), "....G.....\n..........\nGGG...GGGG");
+    }
 }
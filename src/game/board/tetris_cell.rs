@@ -1,5 +1,8 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use wasm_bindgen::prelude::*;
 
+use crate::game::TetrisError;
+
 #[wasm_bindgen]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TetrisCell {
@@ -12,6 +15,8 @@ pub enum TetrisCell {
     Cyan = "cyan",
     Orange = "orange",
     Yellow = "yellow",
+    Warning = "#ff7f7f", // 다음 미노 출현 미리보기에서 충돌하는 칸
+    Garbage = "gray",    // 아래에서 올라오는 쓰레기 줄
 }
 
 impl Default for TetrisCell {
@@ -21,9 +26,9 @@ impl Default for TetrisCell {
 }
 
 impl std::convert::TryFrom<i32> for TetrisCell {
-    type Error = ();
+    type Error = TetrisError;
 
-    fn try_from(value: i32) -> Result<Self, ()> {
+    fn try_from(value: i32) -> Result<Self, TetrisError> {
         match value {
             0 => Ok(TetrisCell::Empty),
             1 => Ok(TetrisCell::Red),
@@ -34,11 +39,28 @@ impl std::convert::TryFrom<i32> for TetrisCell {
             6 => Ok(TetrisCell::Orange),
             7 => Ok(TetrisCell::Yellow),
             8 => Ok(TetrisCell::Ghost),
-            _ => Err(()),
+            9 => Ok(TetrisCell::Warning),
+            10 => Ok(TetrisCell::Garbage),
+            _ => Err(TetrisError::InvalidCellCode(value)),
         }
     }
 }
 
+// 색상 문자열 대신 셀 코드(into_code)로 직렬화해서 저장/전송 크기를 줄임
+impl Serialize for TetrisCell {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.into_code())
+    }
+}
+
+impl<'de> Deserialize<'de> for TetrisCell {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = i32::deserialize(deserializer)?;
+
+        TetrisCell::try_from(code).map_err(serde::de::Error::custom)
+    }
+}
+
 impl TetrisCell {
     pub fn is_empty(&self) -> bool {
         self == &Self::Empty
@@ -55,10 +77,79 @@ impl TetrisCell {
             Self::Orange => 6,
             Self::Yellow => 7,
             Self::Ghost => 8,
+            Self::Warning => 9,
+            Self::Garbage => 10,
             _ => 0,
         }
     }
 
+    // 좌우를 뒤집었을 때의 칸. 뒤집으면 S와 Z, J와 L의 모양이 서로 바뀌므로 색도 바꿈
+    pub fn mirrored(&self) -> Self {
+        match self {
+            Self::Green => Self::Red,
+            Self::Red => Self::Green,
+            Self::Blue => Self::Orange,
+            Self::Orange => Self::Blue,
+            cell => *cell,
+        }
+    }
+
+    // 색상 대신 모양으로도 구분할 수 있도록 블럭 안에 표시할 글자. 미노 블럭이 아니면 None
+    pub fn glyph(&self) -> Option<String> {
+        let glyph = match self {
+            Self::Cyan => "I",
+            Self::Blue => "J",
+            Self::Orange => "L",
+            Self::Yellow => "O",
+            Self::Green => "S",
+            Self::Purple => "T",
+            Self::Red => "Z",
+            _ => return None,
+        };
+
+        Some(glyph.into())
+    }
+
+    // 보드를 글자로 나타낼 때 쓰는 문자
+    // 빈 칸은 '.', 미노 색은 미노 이름(I, J, L, O, S, T, Z), 쓰레기 줄은 'G', 고스트는 '+', 경고는 '!'
+    pub fn to_ascii(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::Cyan => 'I',
+            Self::Blue => 'J',
+            Self::Orange => 'L',
+            Self::Yellow => 'O',
+            Self::Green => 'S',
+            Self::Purple => 'T',
+            Self::Red => 'Z',
+            Self::Garbage => 'G',
+            Self::Ghost => '+',
+            Self::Warning => '!',
+            _ => '.',
+        }
+    }
+
+    // to_ascii의 반대. 알 수 없는 문자면 에러
+    pub fn from_ascii(character: char) -> Result<Self, TetrisError> {
+        match character {
+            '.' => Ok(Self::Empty),
+            'I' => Ok(Self::Cyan),
+            'J' => Ok(Self::Blue),
+            'L' => Ok(Self::Orange),
+            'O' => Ok(Self::Yellow),
+            'S' => Ok(Self::Green),
+            'T' => Ok(Self::Purple),
+            'Z' => Ok(Self::Red),
+            'G' => Ok(Self::Garbage),
+            '+' => Ok(Self::Ghost),
+            '!' => Ok(Self::Warning),
+            _ => Err(TetrisError::ParseError(format!(
+                "unknown board character: {:?}",
+                character
+            ))),
+        }
+    }
+
     pub fn to_color(&self) -> &str {
         match self {
             Self::Empty => "white",
@@ -70,7 +161,63 @@ impl TetrisCell {
             Self::Orange => "orange",
             Self::Yellow => "yellow",
             Self::Ghost => "#d3d3d3",
+            Self::Warning => "#ff7f7f",
+            Self::Garbage => "gray",
             _ => "white",
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_code_reports_the_value() {
+        assert_eq!(
+            TetrisCell::try_from(11),
+            Err(TetrisError::InvalidCellCode(11))
+        );
+        assert_eq!(
+            TetrisCell::try_from(-1),
+            Err(TetrisError::InvalidCellCode(-1))
+        );
+        assert_eq!(
+            TetrisError::InvalidCellCode(42).to_string(),
+            "invalid cell code: 42"
+        );
+    }
+
+    const ALL_CELLS: [TetrisCell; 11] = [
+        TetrisCell::Empty,
+        TetrisCell::Ghost,
+        TetrisCell::Red,
+        TetrisCell::Green,
+        TetrisCell::Blue,
+        TetrisCell::Purple,
+        TetrisCell::Cyan,
+        TetrisCell::Orange,
+        TetrisCell::Yellow,
+        TetrisCell::Warning,
+        TetrisCell::Garbage,
+    ];
+
+    #[test]
+    fn code_round_trips_for_every_cell() {
+        for cell in ALL_CELLS {
+            assert_eq!(TetrisCell::try_from(cell.into_code()), Ok(cell));
+            assert_eq!(TetrisCell::from_ascii(cell.to_ascii()), Ok(cell));
+        }
+    }
+
+    #[test]
+    fn serializes_as_the_code() {
+        for cell in ALL_CELLS {
+            let json = serde_json::to_string(&cell).unwrap();
+
+            assert_eq!(json, cell.into_code().to_string());
+            assert_eq!(serde_json::from_str::<TetrisCell>(&json).unwrap(), cell);
+        }
+        assert!(serde_json::from_str::<TetrisCell>("11").is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use crate::constants::auto_play::{
+    AGGREGATE_HEIGHT_WEIGHT, BUMPINESS_WEIGHT, HOLE_WEIGHT, LINES_CLEARED_WEIGHT,
+};
+use crate::game::{valid_mino, Event, MinoShape, Point, TetrisBoard};
+
+// 자동 플레이 봇이 고른 자리와 그 자리까지 가는 입력
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestedMove {
+    pub rotation_count: usize, // 목표 방향. 출현 방향부터 시계방향 회전 수 (0, R, 2, L)
+    pub x: i64,                // 목표 방향의 미노 4x4 영역이 놓일 열
+    pub events: Vec<Event>,    // 회전 → 한칸씩 이동 → 하드드랍 순서의 입력
+}
+
+// 미노를 내려놓은 뒤의 보드 평가 점수. 클수록 좋음
+pub fn placement_score(board: &TetrisBoard, lines_cleared: u32) -> f64 {
+    let row_count = (board.row_count as usize).min(board.cells.len());
+
+    let mut heights = vec![0i64; board.column_count as usize];
+    let mut holes = 0;
+
+    for (x, height) in heights.iter_mut().enumerate() {
+        let top = (0..row_count).find(|&y| !board.cells[y][x].is_empty());
+
+        if let Some(top) = top {
+            *height = (row_count - top) as i64;
+            // 블럭 아래에 막힌 빈 칸
+            holes += (top..row_count)
+                .filter(|&y| board.cells[y][x].is_empty())
+                .count();
+        }
+    }
+
+    let aggregate_height: i64 = heights.iter().sum();
+    let bumpiness: i64 = heights
+        .windows(2)
+        .map(|pair| (pair[0] - pair[1]).abs())
+        .sum();
+
+    AGGREGATE_HEIGHT_WEIGHT * aggregate_height as f64
+        + LINES_CLEARED_WEIGHT * lines_cleared as f64
+        + HOLE_WEIGHT * holes as f64
+        + BUMPINESS_WEIGHT * bumpiness as f64
+}
+
+// position에 있는 미노를 돌리고 옮긴 뒤 하드드랍하는 모든 자리 중 평가 점수가 가장 높은 자리
+// 벽차기 없이 제자리에서 돌리고, 같은 높이에서 좌우로 옮겨서 닿을 수 있는 자리만 고려
+// 점수가 같다면 입력이 적은 자리를 고름. 놓을 수 있는 자리가 없다면 None
+pub fn suggest_placement(
+    board: &TetrisBoard,
+    mino: &MinoShape,
+    position: Point,
+) -> Option<SuggestedMove> {
+    let orientations = mino.orientations();
+    let mut best: Option<(f64, SuggestedMove)> = None;
+
+    // 입력이 적은 회전부터 (그대로, 오른쪽, 왼쪽, 180도)
+    for turn in [0, 1, 3, 2] {
+        let rotation_count = (mino.rotation_count + turn) % 4;
+        let cells = &orientations[rotation_count].cells;
+
+        if !valid_mino(board, cells, position) {
+            continue;
+        }
+
+        let rotate_event = match turn {
+            1 => Some(Event::RightRotate),
+            2 => Some(Event::DoubleRotate),
+            3 => Some(Event::LeftRotate),
+            _ => None,
+        };
+
+        // 한쪽 방향으로 막힐 때까지 옮기면서 닿는 열을 모음
+        let mut targets = vec![(position.x, 0i64)];
+        for step in [-1i64, 1] {
+            let mut distance = 1;
+            while valid_mino(board, cells, position.add_x(step * distance)) {
+                targets.push((position.x + step * distance, step * distance));
+                distance += 1;
+            }
+        }
+        targets.sort_by_key(|&(_, shift)| shift.abs());
+
+        for (x, shift) in targets {
+            let mut drop_point = Point { x, y: position.y };
+            while valid_mino(board, cells, drop_point.add_y(1)) {
+                drop_point = drop_point.add_y(1);
+            }
+
+            let mut placed = board.clone();
+            placed.write_current_mino(*cells, drop_point);
+            let lines_cleared = placed.clear_lines() as u32;
+            let score = placement_score(&placed, lines_cleared);
+
+            if best
+                .as_ref()
+                .is_some_and(|(best_score, _)| score <= *best_score)
+            {
+                continue;
+            }
+
+            let (press, release) = if shift < 0 {
+                (Event::LeftMove, Event::LeftRelease)
+            } else {
+                (Event::RightMove, Event::RightRelease)
+            };
+
+            let mut events: Vec<Event> = rotate_event.into_iter().collect();
+            for _ in 0..shift.abs() {
+                events.push(press);
+                events.push(release);
+            }
+            events.push(Event::HardDrop);
+
+            best = Some((
+                score,
+                SuggestedMove {
+                    rotation_count,
+                    x,
+                    events,
+                },
+            ));
+        }
+    }
+
+    best.map(|(_, suggested_move)| suggested_move)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_board() -> TetrisBoard {
+        TetrisBoard::from_ascii(&[".........."; 24].join("\n")).unwrap()
+    }
+
+    // 블럭 아래에 막힌 빈 칸 수
+    fn hole_count(board: &TetrisBoard) -> usize {
+        (0..board.column_count as usize)
+            .map(|x| {
+                let column: Vec<_> = board.cells.iter().map(|row| row[x]).collect();
+                match column.iter().position(|cell| !cell.is_empty()) {
+                    Some(top) => column[top..].iter().filter(|cell| cell.is_empty()).count(),
+                    None => 0,
+                }
+            })
+            .sum()
+    }
+
+    #[test]
+    fn does_not_leave_holes_on_a_flat_board() {
+        // S와 Z는 평평한 바닥 어디에 놓아도 구멍이 생기므로 제외
+        for mino in [
+            MinoShape::I,
+            MinoShape::J,
+            MinoShape::L,
+            MinoShape::O,
+            MinoShape::T,
+        ] {
+            let board = empty_board();
+            let suggested = suggest_placement(&board, &mino, Point { x: 3, y: 2 }).unwrap();
+
+            let cells = mino.orientations()[suggested.rotation_count].cells;
+            let mut drop_point = Point {
+                x: suggested.x,
+                y: 2,
+            };
+            while valid_mino(&board, &cells, drop_point.add_y(1)) {
+                drop_point = drop_point.add_y(1);
+            }
+            let mut placed = board.clone();
+            placed.write_current_mino(cells, drop_point);
+
+            assert_eq!(hole_count(&placed), 0, "{:?}", mino.mino);
+            assert_eq!(suggested.events.last(), Some(&Event::HardDrop));
+        }
+    }
+
+    #[test]
+    fn holes_lower_the_placement_score() {
+        let flat = TetrisBoard::from_ascii("....\n....\nIIII").unwrap();
+        let holed = TetrisBoard::from_ascii("....\nIIII\n....").unwrap();
+
+        assert!(placement_score(&flat, 0) > placement_score(&holed, 0));
+    }
+}
@@ -0,0 +1,104 @@
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, VecDeque};
+
+use crate::game::{Mino, MinoShape, MinoShapeCells, Point};
+
+// 미노가 놓인 자리. (열, 미노의 맨 윗칸 기준 행) 목록이라 방향이 달라도 같은 칸을 차지하면 같은 값
+pub type Footprint = Vec<(i64, usize)>;
+
+// x열에 놓인 미노가 차지하는 자리. 칸이 없는 모양이면 빈 목록
+pub fn footprint(cells: &MinoShapeCells, x: i64) -> Footprint {
+    let top = cells
+        .iter()
+        .position(|row| row.iter().any(|cell| !cell.is_empty()));
+
+    let top = match top {
+        Some(top) => top,
+        None => return vec![],
+    };
+
+    let mut footprint = vec![];
+    for (y, row) in cells.iter().enumerate().skip(top) {
+        for (dx, cell) in row.iter().enumerate() {
+            if !cell.is_empty() {
+                footprint.push((x + dx as i64, y - top));
+            }
+        }
+    }
+
+    footprint
+}
+
+// 빈 보드의 출현 위치에서 각 자리까지 필요한 최소 입력 수 (피네스 표)
+// 한칸 이동, 벽까지 이동(DAS), 왼쪽/오른쪽/180도 회전을 각각 입력 한번으로 셈. 벽차기 없이 제자리 회전만 고려
+pub fn finesse_table(
+    mino: Mino,
+    column_count: u32,
+    spawn_column_offset: i32,
+) -> HashMap<Footprint, u32> {
+    let orientations = MinoShape::from(i32::from(mino)).orientations();
+    let in_board = |x: i64, orientation: usize| {
+        footprint(&orientations[orientation].cells, x)
+            .iter()
+            .all(|&(column, _)| column >= 0 && column < column_count as i64)
+    };
+
+    let start = (
+        Point::start_point(column_count, 0, spawn_column_offset).x,
+        0,
+    );
+    let mut distance = HashMap::from([(start, 0u32)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some((x, orientation)) = queue.pop_front() {
+        let cost = distance[&(x, orientation)];
+
+        let mut next_states = vec![];
+        for dx in [-1, 1] {
+            if in_board(x + dx, orientation) {
+                next_states.push((x + dx, orientation));
+
+                let mut wall_x = x + dx;
+                while in_board(wall_x + dx, orientation) {
+                    wall_x += dx;
+                }
+                next_states.push((wall_x, orientation));
+            }
+        }
+        for turn in [1, 2, 3] {
+            let next_orientation = (orientation + turn) % 4;
+            if in_board(x, next_orientation) {
+                next_states.push((x, next_orientation));
+            }
+        }
+
+        for state in next_states {
+            if let Entry::Vacant(entry) = distance.entry(state) {
+                entry.insert(cost + 1);
+                queue.push_back(state);
+            }
+        }
+    }
+
+    let mut table = HashMap::new();
+    for ((x, orientation), cost) in distance {
+        let entry = table
+            .entry(footprint(&orientations[orientation].cells, x))
+            .or_insert(cost);
+        *entry = (*entry).min(cost);
+    }
+
+    table
+}
+
+// x열에 놓인 미노를 빈 보드에서 출현 위치부터 옮기는 데 필요한 최소 입력 수. 닿을 수 없는 자리면 None
+pub fn minimal_input_count(
+    mino_shape: &MinoShape,
+    x: i64,
+    column_count: u32,
+    spawn_column_offset: i32,
+) -> Option<u32> {
+    finesse_table(mino_shape.mino, column_count, spawn_column_offset)
+        .get(&footprint(&mino_shape.cells, x))
+        .copied()
+}
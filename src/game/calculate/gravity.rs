@@ -0,0 +1,10 @@
+use crate::constants::time::MIN_TICK_INTERVAL;
+
+// 레벨별 틱 간격(밀리초). 가이드라인 중력 공식 (0.8 - (레벨 - 1) * 0.007) ^ (레벨 - 1) 초를 사용
+// 레벨 1에서 1000ms이고, 레벨이 오를수록 짧아지다가 MIN_TICK_INTERVAL에서 멈춤
+pub fn level_tick_interval(level: u32) -> u64 {
+    let level = level.max(1) as f64;
+    let seconds = (0.8 - (level - 1.0) * 0.007).max(0.0).powf(level - 1.0);
+
+    ((seconds * 1000.0).round() as u64).max(MIN_TICK_INTERVAL)
+}
@@ -3,3 +3,12 @@ pub use valid_mino::*;
 
 pub mod score;
 pub use score::*;
+
+pub mod gravity;
+pub use gravity::*;
+
+pub mod finesse;
+pub use finesse::*;
+
+pub mod auto_play;
+pub use auto_play::*;
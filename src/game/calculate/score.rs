@@ -1,53 +1,77 @@
 use crate::game::SpinType;
 
-pub fn calculate_score(
-    line: u8,
-    is_perfect: bool,
-    combo: Option<u32>,
-    spin_type: SpinType,
-    back2back: Option<u32>,
-) -> u64 {
-    let mut score = 0;
+// 스핀 없이 지운 줄 수에 따른 이름
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineClear {
+    Single,
+    Double,
+    Triple,
+    Tetris,
+}
 
-    if is_perfect {
-        score += 1000;
+impl LineClear {
+    pub fn from_lines(line: u8) -> Option<Self> {
+        match line {
+            1 => Some(Self::Single),
+            2 => Some(Self::Double),
+            3 => Some(Self::Triple),
+            4 => Some(Self::Tetris),
+            _ => None,
+        }
     }
 
-    let back2back_bonus = match back2back {
-        Some(back2back) => back2back * 100,
-        None => 0,
-    } as u64;
-
-    match spin_type {
-        SpinType::None => match line {
-            1 => score += 10,
-            2 => score += 20,
-            3 => score += 30,
-            4 => {
-                score += 100;
-            }
-            _ => {}
-        },
-        SpinType::Spin => match line {
-            1 => score += 50 + back2back_bonus,
-            2 => score += 100 + back2back_bonus,
-            _ => {}
-        },
-        SpinType::TSpin => match line {
-            1 => score += 500 + back2back_bonus,
-            2 => score += 1000 + back2back_bonus,
-            3 => score += 2000 + back2back_bonus,
-            _ => {}
-        },
-        SpinType::Mini => match line {
-            1 => score += 50 + back2back_bonus,
-            2 => score += 100 + back2back_bonus,
+    // 레벨 1 기준 점수. 실제 점수는 레벨을 곱함
+    pub fn base_score(&self) -> u64 {
+        match self {
+            Self::Single => 100,
+            Self::Double => 300,
+            Self::Triple => 500,
+            Self::Tetris => 800,
+        }
+    }
+
+    // 줄을 지운 뒤 보드가 완전히 비었을 때(퍼펙트 클리어) 추가로 받는 레벨 1 기준 점수
+    pub fn perfect_clear_score(&self) -> u64 {
+        match self {
+            Self::Single => 800,
+            Self::Double => 1200,
+            Self::Triple => 1800,
+            Self::Tetris => 2000,
+        }
+    }
+}
+
+// T스핀으로 고정했을 때의 레벨 1 기준 점수. 줄을 지우지 못해도 점수가 있음. 실제 점수는 레벨을 곱함
+pub fn tspin_base_score(spin_type: &SpinType, line: u8) -> u64 {
+    match (spin_type, line) {
+        (SpinType::TSpin, 0) => 400,
+        (SpinType::TSpin, 1) => 800,
+        (SpinType::TSpin, 2) => 1200,
+        (SpinType::TSpin, 3) => 1600,
+        (SpinType::Mini, 0) => 100,
+        (SpinType::Mini, 1) => 200,
+        (SpinType::Mini, 2) => 400,
+        _ => 0,
+    }
+}
+
+// 스핀, 콤보 보너스 점수
+// 지운 줄과 T스핀 점수(백투백 포함), 퍼펙트 클리어 점수는 GameInfo에서 따로 계산
+pub fn calculate_score(line: u8, combo: Option<u32>, spin_type: SpinType, level: u32) -> u64 {
+    let mut score = 0;
+
+    // T스핀이 아닌 일반 스핀 보너스
+    if let SpinType::Spin = spin_type {
+        match line {
+            1 => score += 50,
+            2 => score += 100,
             _ => {}
-        },
+        }
     }
 
+    // 콤보 보너스는 콤보 수 x 50점에 레벨을 곱함 (첫 줄 삭제는 콤보 0이므로 보너스 없음)
     if let Some(combo) = combo {
-        score += combo as u64 * 10;
+        score += combo as u64 * 50 * level as u64;
     }
 
     score
@@ -1,72 +1,50 @@
-use crate::game::{MinoShapeCells, Point, TetrisBoard, TetrisCell, SpinType, MinoShape};
+use crate::game::{
+    occupied_points, MinoShape, MinoShapeCells, PlacementError, Point, SpinType, TetrisBoard,
+};
 
 // 미노 충돌여부 검증
 pub fn valid_mino(board: &TetrisBoard, mino: &MinoShapeCells, point: Point) -> bool {
-    let mino_row_count = mino.len();
-    let mino_column_count = mino[0].len();
+    valid_mino_detailed(board, mino, point).is_ok()
+}
 
+// 미노 충돌여부 검증. 놓을 수 없다면 처음 발견한 이유를 반환
+pub fn valid_mino_detailed(board: &TetrisBoard, mino: &MinoShapeCells, point: Point) -> Result<(), PlacementError> {
     let column_count = board.column_count as usize;
     let center_index = column_count / 2;
     let above_full = board.cells[0][center_index - 2..center_index + 2]
         .iter()
         .any(|e| !e.is_empty());
 
-    for (mino_x, x) in (point.x..(point.x + mino_row_count as i64)).enumerate() {
-        for (mino_y, y) in (point.y..(point.y + mino_column_count as i64)).enumerate() {
-            let mino_is_empty = TetrisCell::Empty == mino[mino_y][mino_x];
-
-            if mino_is_empty {
-                continue;
-            }
-
-            if x < 0 || y >= board.row_count as i64 {
-                if mino_is_empty {
-                    continue;
-                } else {
-                    return false;
-                }
-            }
-
-            let above_board = y < 0; // 위로 초과
-            let next_board = x < 0 || x >= board.column_count.into(); // 옆으로 초과
-
-            let y = y as usize;
-            let x = x as usize;
+    for Point { x, y } in occupied_points(mino, point) {
+        // 보드 밖이라면 어느 쪽으로 나갔는지 좌표로 판단
+        if x < 0 {
+            return Err(PlacementError::OutOfLeft);
+        }
 
-            let cell = board.cells.get(y).map(|e| e.get(x)).flatten();
+        if x >= board.column_count.into() {
+            return Err(PlacementError::OutOfRight);
+        }
 
-            match cell {
-                Some(cell) => {
-                    // 비어있는 영역에 시도는 유효
-                    if let TetrisCell::Empty = cell {
-                        continue;
-                    }
-                    // 유효하지 않은 블럭 충돌
-                    else {
-                        return false;
-                    }
-                }
-                None => {
-                    if above_board {
-                        if above_full {
-                            return false;
-                        }
+        if y >= board.row_count as i64 {
+            return Err(PlacementError::OutOfBottom);
+        }
 
-                        if next_board {
-                            return false;
-                        }
+        // 위로 초과. 출현 영역이 막혀있지 않다면 유효
+        if y < 0 {
+            if above_full {
+                return Err(PlacementError::BlockedAbove);
+            }
 
-                        continue;
-                    }
+            continue;
+        }
 
-                    // 미노가 존재함에도 존재하지 않는 영역에 침범 시도
-                    return false;
-                }
-            }
+        // 유효하지 않은 블럭 충돌
+        if !board.cells[y as usize][x as usize].is_empty() {
+            return Err(PlacementError::Overlap);
         }
     }
 
-    true
+    Ok(())
 }
 
 pub fn valid_tspin(board: &TetrisBoard, mino: &MinoShape, point: Point, kick_try: usize) -> SpinType {
@@ -110,4 +88,74 @@ pub fn valid_tspin(board: &TetrisBoard, mino: &MinoShape, point: Point, kick_try
         }
     }
     else {return SpinType::None;}
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn board(text: &str) -> TetrisBoard {
+        TetrisBoard::from_ascii(text).unwrap()
+    }
+
+    #[test]
+    fn accepts_empty_cells() {
+        let t = &MinoShape::T.cells;
+        let board = board("......\n......\n......\n......");
+
+        assert!(valid_mino(&board, t, Point { x: 0, y: 0 }));
+        assert!(valid_mino(&board, t, Point { x: 3, y: 2 }));
+    }
+
+    #[test]
+    fn rejects_cells_outside_the_board() {
+        let t = &MinoShape::T.cells;
+        let board = board("......\n......\n......\n......");
+
+        assert!(!valid_mino(&board, t, Point { x: -1, y: 0 }));
+        assert!(!valid_mino(&board, t, Point { x: 4, y: 0 }));
+        assert!(!valid_mino(&board, t, Point { x: 0, y: 3 }));
+    }
+
+    #[test]
+    fn rejects_locked_cells() {
+        let t = &MinoShape::T.cells;
+        let board = board("......\n......\n......\n.G....");
+
+        assert!(!valid_mino(&board, t, Point { x: 0, y: 2 }));
+        assert!(valid_mino(&board, t, Point { x: 2, y: 2 }));
+    }
+
+    #[test]
+    fn empty_part_of_the_area_may_leave_the_board() {
+        let t = &MinoShape::T.cells;
+        let board = board("......\n......\n......\n......");
+
+        // T의 4x4 영역 중 오른쪽 열과 아래 두 행은 비어있음
+        assert!(valid_mino(&board, t, Point { x: 3, y: 0 }));
+        assert!(valid_mino(&board, t, Point { x: 0, y: 2 }));
+    }
+
+    #[test]
+    fn reports_the_side_the_mino_left_the_board() {
+        let t = &MinoShape::T.cells;
+        let board = board("......\n......\n......\n......");
+        let error = |x, y| valid_mino_detailed(&board, t, Point { x, y });
+
+        assert_eq!(error(-1, 0), Err(PlacementError::OutOfLeft));
+        assert_eq!(error(4, 0), Err(PlacementError::OutOfRight));
+        assert_eq!(error(5, -1), Err(PlacementError::OutOfRight));
+        assert_eq!(error(0, 3), Err(PlacementError::OutOfBottom));
+    }
+
+    #[test]
+    fn reports_overlap_and_blocked_spawn_area() {
+        let t = &MinoShape::T.cells;
+        let board = board(".G....\n......\n......\n.G....");
+        let error = |x, y| valid_mino_detailed(&board, t, Point { x, y });
+
+        assert_eq!(error(0, 2), Err(PlacementError::Overlap));
+        assert_eq!(error(3, -1), Err(PlacementError::BlockedAbove));
+        assert_eq!(error(3, 1), Ok(()));
+    }
+}
@@ -1,11 +1,48 @@
 // 키보드 제어 이벤트
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Event {
     LeftMove,
     RightMove,
+    LeftRelease,  // 왼쪽 이동키 뗌 (DAS 해제)
+    RightRelease, // 오른쪽 이동키 뗌 (DAS 해제)
     LeftRotate,
     RightRotate,
     SoftDrop,
+    SoftDropRelease, // 소프트드랍 키 뗌 (빠른 중력 해제)
     HardDrop,
     DoubleRotate,
     Hold,
 }
+
+impl Event {
+    // 좌우를 뒤집은 입력. 화면이 뒤집히면 회전 방향도 반대로 보이므로 같이 바꿈
+    pub fn mirrored(self) -> Self {
+        match self {
+            Event::LeftMove => Event::RightMove,
+            Event::RightMove => Event::LeftMove,
+            Event::LeftRelease => Event::RightRelease,
+            Event::RightRelease => Event::LeftRelease,
+            Event::LeftRotate => Event::RightRotate,
+            Event::RightRotate => Event::LeftRotate,
+            event => event,
+        }
+    }
+
+    // 한 프레임에 같이 들어온 입력을 처리하는 순서. 작은 값부터 처리하고, 같은 값끼리는 들어온 순서 유지
+    // 홀드 → 이동/회전 → 소프트드랍 → 하드드랍 순서이므로
+    // 홀드와 하드드랍이 같은 프레임에 들어오면 항상 홀드로 바뀐 미노가 떨어짐
+    pub fn priority(&self) -> u8 {
+        match self {
+            Event::Hold => 0,
+            Event::LeftMove
+            | Event::RightMove
+            | Event::LeftRelease
+            | Event::RightRelease
+            | Event::LeftRotate
+            | Event::RightRotate
+            | Event::DoubleRotate => 1,
+            Event::SoftDrop | Event::SoftDropRelease => 2,
+            Event::HardDrop => 3,
+        }
+    }
+}
@@ -1,39 +1,82 @@
 use std::collections::VecDeque;
+use std::time::Duration;
+
+use instant::Instant;
 
 use crate::game::{
-    valid_mino, valid_tspin, BagType, ClearInfo, GameRecord, MinoShape, Point, SpinType,
-    TetrisBoard, TetrisCell,
+    valid_mino, valid_tspin, AutoShift, BagType, CellTimes, ClearInfo, GameRecord, GameSnapshot,
+    GameStats, GameView, MinoShape, Point, RotateDirection, SequenceState, ShiftDirection,
+    SpinType, TetrisBoard, TetrisCell, TetrisError, TimedEvent, TopOut, GAME_SNAPSHOT_VERSION,
 };
 
-use crate::js_bind::write_text::write_text;
+use crate::constants::board::{
+    CLASSIC_HISTORY_SIZE, CLASSIC_ROLL_COUNT, LINES_PER_LEVEL, SPAWN_PEEK_ROW_COUNT,
+};
+use crate::constants::time::MIN_TICK_INTERVAL;
+use crate::options::adaptive_speed::AdaptiveSpeed;
+use crate::options::block_style::BlockStyle;
+use crate::options::cheese_race::CheeseRace;
+use crate::options::fading_blocks::FadingBlocks;
 use crate::options::game_option::GameOption;
-use crate::util::{random, rotate_left, rotate_right, KICK_INDEX_3BY3, KICK_INDEX_I};
+use crate::options::garbage_challenge::GarbageChallenge;
+use crate::options::ghost_style::GhostStyle;
+use crate::options::key_bindings::KeyBindings;
+use crate::options::lock_delay_policy::LockDelayPolicy;
+use crate::options::theme::Theme;
+use crate::util::{
+    random, random_seed, rotate_left, rotate_right, Rng, XorShiftRng, KICK_INDEX_180,
+    KICK_INDEX_3BY3, KICK_INDEX_I,
+};
 
-use super::{calculate_score, Mino};
+use super::{
+    calculate_score, level_tick_interval, minimal_input_count, suggest_placement, tspin_base_score,
+    Event, LineClear, Mino, RealtimeReplay, SuggestedMove,
+};
 
 #[derive(Debug)]
 pub struct GameInfo {
     pub record: GameRecord,
+    pub level: u32,               // 현재 레벨 (1부터 시작). 줄 삭제 점수에 곱해짐
+    pub lines_cleared_total: u32, // 이번 게임에서 지운 줄 수. LINES_PER_LEVEL마다 레벨이 오름
 
-    pub running_time: u128, // 실행시간 (밀리초)
+    pub running_time: Duration, // 누적된 실행시간 (일시정지 구간 제외)
+    pub timer_resumed_at: Option<Instant>, // 시간 측정을 마지막으로 (재)개한 시점. 멈춰있으면 None
+    pub logic_time: u64, // 게임 로직이 진행된 시간 (밀리초). advance로만 증가하므로 리플레이에서도 동일
+    pub gravity_elapsed: u64, // 마지막 중력 틱 이후 쌓인 시간 (밀리초)
+    pub former_lock_delay_count: u8, // 마지막으로 확인한 록딜레이 카운트. 바뀌면 중력 시간을 초기화
 
     pub on_play: bool,                   //게임 진행중 여부
+    pub loop_generation: u32,            //게임 시작 횟수. 바뀌면 이전 루프 종료
+    pub dirty: bool,                     //마지막 렌더링 이후 화면에 보이는 상태가 바뀌었는지 여부
+    pub paused: bool,                    //일시정지 여부 (시간 정지, 입력 무시)
+    pub auto_pause: bool,                //포커스를 잃으면 자동 일시정지
+    pub show_help: bool,                 //조작법 도움말 표시 여부 (표시 중 일시정지)
     pub current_position: Point,         //현재 미노 좌표
     pub current_mino: Option<MinoShape>, //현재 미노 형태
 
-    pub freezed: bool, //현재 미노가 보드에 붙었는지?
+    pub freezed: bool, //현재 미노가 바닥에 닿아 고정 대기중인지 여부. 록딜레이가 지나도 닿아있으면 고정
     pub lose: bool,    //현재 게임 오버 여부
+    pub win: bool,     //치즈 레이스 등 목표 달성 여부
 
-    pub next_count: i32,          // 넥스트 개수
+    pub next_buffer_count: usize, // 가방에 미리 만들어둘 넥스트 개수 (AI/미리보기용)
+    pub next_preview_count: usize, // 화면에 보여줄 넥스트 개수
     pub bag: VecDeque<MinoShape>, // 현재 가방
 
     pub tetris_board: TetrisBoard, //테트리스 보드
 
-    pub render_interval: u64, //렌더링 시간간격(밀리초)
-    pub tick_interval: u64,   //틱당 시간간격(밀리초)
+    pub render_interval: u64,                  //렌더링 최소 간격(밀리초)
+    pub tick_interval: u64,                    //틱당 시간간격(밀리초)
+    pub base_tick_interval: u64,               // 속도 조절 전 기준 틱 간격(밀리초)
+    pub adaptive_speed: Option<AdaptiveSpeed>, // 보드가 찬 정도에 따른 속도 조절 설정
 
-    pub bag_mode: BagType, //가방 순환 규칙 사용여부 (false면 완전 랜덤. true면 한 묶음에서 랜덤)
+    pub bag_mode: BagType, //미노 순서를 정하는 규칙 (완전 랜덤, 7가방, 14가방, 기록 방식)
     pub mino_list: Vec<MinoShape>, //미노 리스트
+    pub rng: Box<dyn Rng>, // 가방을 섞을 때 사용하는 난수 생성기
+    pub rng_seed: u64,     // 난수 생성기 시드 (리플레이 재현용)
+    // 옵션으로 고정한 시드. None이면 판마다 새 시드를 뽑음
+    pub fixed_seed: Option<u64>,
+    pub input_log: Vec<TimedEvent>, // 게임 진행 시간과 함께 기록된 입력 목록
+    pub pending_events: Vec<Event>, // 아직 처리하지 않은 이번 프레임의 입력 목록
 
     pub hold: Option<MinoShape>, // 홀드한 미노
     pub hold_used: bool,         // 현재 홀드 사용권을 소모했는지 여부
@@ -43,29 +86,95 @@ pub struct GameInfo {
 
     pub message: Option<String>, // 렌더링할 메세지
 
-    pub in_spin: SpinType, // 현재 스핀 상태 확인
+    pub in_spin: SpinType,              // 현재 스핀 상태 확인
+    pub last_action_was_rotation: bool, // 마지막으로 성공한 동작이 회전인지 여부. 회전 후 이동했다면 스핀으로 치지 않음
+
+    pub piece_input_count: u32, // 현재 미노에 누른 이동/회전 키 횟수 (DAS로 밀고 있는 동안은 한번)
+    pub piece_soft_dropped: bool, // 현재 미노에 소프트드랍을 썼는지 여부. 썼다면 피네스를 따지지 않음
+
+    pub lock_delay: u32,      // 바닥에 닿을때 고정하기까지의 딜레이. 밀리초 단위.
+    pub lock_delay_count: u8, // 하좌우이동, 좌우회전 성공 시 록딜레이 카운트가 올라감. 틱스레드에서 변화를 읽고 lock_delay_policy에 따라 start를 초기화
+    pub lock_delay_policy: LockDelayPolicy, // 록딜레이 초기화 규칙
+
+    pub sdf: u32,                      // soft drop factor. 소프트드랍 중 중력 배수
+    pub soft_dropping: bool,           // 소프트드랍 키를 누르고 있는지 여부
+    pub das: u32,                      // delay auto shift. 밀리초 단위.
+    pub arr: u32,                      // auto repeat shift. 좌우 이동 클릭시,
+    pub auto_shift: Option<AutoShift>, // 현재 누르고 있는 좌우 이동키 상태. 미노가 바뀌어도 유지
+
+    pub lock_out_leniency: bool, // 락아웃 시 아래로 한번 더 기회를 줄지 여부 (캐주얼용)
+    pub last_chance_used: bool,  // 현재 미노가 락아웃 기회를 이미 사용했는지 여부
 
-    pub lock_delay: u32, // 바닥에 닿을때 고정하기까지의 딜레이. 밀리초 단위.
-    pub lock_delay_count: u8, // 하좌우이동, 좌우회전 성공 시 록딜레이 카운트가 올라감. 틱스레드에서 변화를 읽고 start를 초기화. 8이상이면 안올라감
+    pub block_style: BlockStyle, // 블럭 테두리 렌더링 설정
+    pub theme: Theme,            // 패널 테두리 등 화면 테마
+    pub ghost_style: GhostStyle, // 고스트 렌더링 방식
+    pub show_grid: bool,         // 보드에 칸 격자선을 그릴지 여부
+    pub mirror: bool, // 거울 모드. 게임 로직은 그대로 두고 화면과 좌우 입력만 뒤집음. 통계와 기록은 로직 기준 미노로 셈
 
-    pub sdf: u32, // soft drop fast. 소프트 드랍 속도
-    pub das: u32, // delay auto shift. 밀리초 단위.
-    pub arr: u32, // auto repeat shift. 좌우 이동 클릭시,
+    pub line_clear_delay: u32, // 지울 줄이 깜빡이는 시간 (밀리초). 0이면 바로 지움
+    pub clearing_rows: Vec<usize>, // 깜빡이는 중인 지울 줄 (중력 멈춤)
+    pub clear_elapsed: u64,    // 줄 삭제 연출이 시작된 뒤 흐른 시간 (밀리초)
+
+    pub instant_respawn: bool, // 줄 삭제 직후 다음 틱을 기다리지 않고 바로 새 미노를 출현시킬지 여부
+
+    pub spawn_peek: bool, // 블럭이 높이 쌓였을 때 다음 미노가 들어올 위치를 미리 보여줄지 여부
+    pub key_bindings: KeyBindings, // 현재 키 설정. 게임 도중 바꾸면 바로 반영됨
+    pub spawn_row: u32,   // 미노가 출현하는 행 (미노 4x4 영역의 맨 위 행)
+    pub spawn_column_offset: i32, // 가운데 출현 열에서 옮길 칸 수
+
+    pub garbage_challenge: Option<GarbageChallenge>, // 무작위 쓰레기 줄 챌린지 설정. None이면 사용 안함
+    pub garbage_rng: XorShiftRng, // 쓰레기 줄 생성용 난수 생성기. 가방과 별개로 챌린지 시드를 사용
+    pub garbage_elapsed: u64,     // 마지막 쓰레기 줄 이후 쌓인 시간 (밀리초)
+    pub garbage_interval: u64,    // 현재 쓰레기 줄 간격 (밀리초). 올라올 때마다 짧아짐
+    pub pending_garbage: VecDeque<(u32, usize)>, // 받아둔 쓰레기 줄 (줄 수, 구멍 위치). 미노가 고정될 때 올라옴
+
+    pub cheese_race: Option<CheeseRace>, // 치즈 레이스 설정. 깔아둔 쓰레기 줄을 모두 지우면 승리
+
+    pub fading_blocks: Option<FadingBlocks>, // 고정된 블럭이 사라지는 모드 설정. None이면 사용 안함
+    pub cell_times: CellTimes,               // 칸마다 블럭이 고정된 시점. 보드와 같이 움직임
 }
 
 impl GameInfo {
     pub fn with_option(option: GameOption) -> Self {
         let column_count = option.column_count;
-        let hidden_row_count = 4;
+        let hidden_row_count = option.hidden_row_count;
+        // 보드의 행 개수는 숨겨진 행을 포함
         let row_count = option.row_count + hidden_row_count;
         let board_height = option.board_height;
         let board_width = option.board_width;
         let bag_mode = option.bag_mode;
+        let lock_out_leniency = option.lock_out_leniency;
+        let lock_delay_policy = option.lock_delay_policy;
+        let block_style = option.block_style;
+        let theme = option.theme;
+        let ghost_style = option.ghost_style;
+        let show_grid = option.show_grid;
+        let mirror = option.mirror;
+        let spawn_peek = option.spawn_peek;
+        let auto_pause = option.auto_pause;
+        let das = option.das;
+        let arr = option.arr;
+        let key_bindings = option.key_bindings;
+        let instant_respawn = option.instant_respawn;
+        let line_clear_delay = option.line_clear_delay;
+        let adaptive_speed = option.adaptive_speed;
+        // 미노 4x4 영역이 보드 밖으로 나가지 않도록 출현 행을 보드 안으로 제한
+        let spawn_row = option.spawn_row.min(row_count.saturating_sub(4));
+        let garbage_challenge = option.garbage_challenge;
+        let cheese_race = option.cheese_race;
+        let fading_blocks = option.fading_blocks;
+        let garbage_seed = garbage_challenge.map(|e| e.seed).unwrap_or_default();
+        let garbage_interval = garbage_challenge
+            .map(|e| e.garbage_interval)
+            .unwrap_or_default();
+        // 시드를 따로 보관해두면 같은 가방 순서로 리플레이할 수 있음
+        let fixed_seed = option.seed;
+        let rng_seed = fixed_seed.unwrap_or_else(random_seed);
+        let next_preview_count = option.next_preview_count;
+        // 보여줄 개수보다 적게 만들어두면 미리보기가 비므로 최소한 보여줄 개수만큼은 유지
+        let next_buffer_count = option.next_buffer_count.max(next_preview_count);
         let tetris_board = TetrisBoard {
-            cells: vec![
-                vec![TetrisCell::Empty; column_count as usize];
-                row_count as usize + hidden_row_count as usize
-            ],
+            cells: vec![vec![TetrisCell::Empty; column_count as usize]; row_count as usize],
             column_count,
             row_count,
             board_height,
@@ -73,43 +182,107 @@ impl GameInfo {
             hidden_row_count,
         };
 
-        let mino_list = vec![
-            MinoShape::I,
-            MinoShape::L,
-            MinoShape::J,
-            MinoShape::S,
-            MinoShape::Z,
-            MinoShape::O,
-            MinoShape::T,
-        ];
+        // 빈 목록이면 가방을 채울 수 없으므로 기본 7종으로 대체
+        let mino_list = if option.mino_list.is_empty() {
+            MinoShape::all()
+        } else {
+            option.mino_list
+        };
+
+        // 출현 위치에서 보드 밖으로 나가는 미노가 있다면 가운데 출현으로 되돌림
+        let spawn_column_offset = {
+            let spawn_x = Point::start_point(column_count, 0, option.spawn_column_offset).x;
+
+            if mino_list
+                .iter()
+                .all(|mino| mino.fits_columns(spawn_x, column_count))
+            {
+                option.spawn_column_offset
+            } else {
+                0
+            }
+        };
 
-        Self {
+        let mut game_info = Self {
             record: Default::default(),
-            render_interval: 200,
+            level: 1,
+            lines_cleared_total: 0,
+            render_interval: 0,
             tick_interval: 1000,
+            base_tick_interval: 1000,
+            adaptive_speed,
             current_position: Default::default(),
             current_mino: None,
             freezed: false,
-            next_count: 5,
+            loop_generation: 0,
+            next_buffer_count,
+            next_preview_count,
             bag: VecDeque::new(),
             tetris_board,
             on_play: false,
+            dirty: true,
+            paused: false,
+            auto_pause,
+            show_help: false,
+            key_bindings,
             lose: false,
+            win: false,
             bag_mode,
             mino_list,
+            rng: Box::new(XorShiftRng::new(rng_seed)),
+            rng_seed,
+            fixed_seed,
+            input_log: vec![],
+            pending_events: vec![],
             hold: None,
             hold_used: false,
             back2back: None,
             combo: None,
             message: None,
             in_spin: SpinType::None,
+            last_action_was_rotation: false,
+            piece_input_count: 0,
+            piece_soft_dropped: false,
             lock_delay: 500,
-            das: 300, //미사용
-            sdf: 0,   //미사용
-            arr: 0,   //미사용
-            running_time: 0,
-            lock_delay_count: 0,  
-        }
+            das,
+            sdf: 20,
+            soft_dropping: false,
+            arr,
+            auto_shift: None,
+            running_time: Duration::ZERO,
+            timer_resumed_at: None,
+            logic_time: 0,
+            gravity_elapsed: 0,
+            former_lock_delay_count: 0,
+            lock_delay_count: 0,
+            lock_delay_policy,
+            lock_out_leniency,
+            last_chance_used: false,
+            block_style,
+            theme,
+            ghost_style,
+            show_grid,
+            mirror,
+            spawn_peek,
+            spawn_row,
+            spawn_column_offset,
+            line_clear_delay,
+            clearing_rows: vec![],
+            clear_elapsed: 0,
+            instant_respawn,
+            garbage_challenge,
+            garbage_rng: XorShiftRng::new(garbage_seed),
+            garbage_elapsed: 0,
+            garbage_interval,
+            pending_garbage: VecDeque::new(),
+            cheese_race,
+            fading_blocks,
+            cell_times: CellTimes::new(column_count, row_count),
+        };
+
+        game_info.fill_cheese();
+
+        game_info
     }
 
     // 가방에서 미노를 새로 가져옴.
@@ -123,96 +296,267 @@ impl GameInfo {
 
     // 가방이 비어있을 경우 충전
     pub fn manage_bag(&mut self) {
-        if self.bag.len() <= self.next_count as usize {
-            self.fill_bag();
+        // 미노 목록이 작으면 한번 채워도 넥스트 개수보다 모자랄 수 있으므로 반복
+        while self.bag.len() <= self.next_buffer_count {
+            if self.fill_bag().is_err() {
+                break;
+            }
+        }
+    }
+
+    // 난수 생성기 교체. 이미 만들어둔 가방은 유지됨
+    pub fn set_rng(&mut self, rng: Box<dyn Rng>) {
+        self.rng = rng;
+    }
+
+    // 주어진 시드로 난수 생성기를 다시 만들고 가방을 비움
+    // 이후 나오는 미노 순서는 시드로만 결정되며, 새 판을 시작해도 같은 시드를 계속 사용함
+    pub fn reseed(&mut self, seed: u64) {
+        self.fixed_seed = Some(seed);
+        self.rng_seed = seed;
+        self.rng = Box::new(XorShiftRng::new(seed));
+        self.bag = VecDeque::new();
+        self.manage_bag();
+        self.dirty = true;
+    }
+
+    // 현재 가방과 난수 생성기 상태를 내보냄. 상태를 내보낼 수 없는 난수 생성기라면 None
+    pub fn export_sequence_state(&self) -> Option<SequenceState> {
+        Some(SequenceState {
+            rng_state: self.rng.export_state()?,
+            bag: self.bag.iter().map(|mino_shape| mino_shape.mino).collect(),
+        })
+    }
+
+    // 내보낸 상태로 가방과 난수 생성기를 되돌림. 이후 나오는 미노 순서가 내보낸 쪽과 같아짐
+    pub fn restore_sequence_state(&mut self, state: &SequenceState) {
+        self.rng = Box::new(XorShiftRng::new(state.rng_state));
+        self.bag = state
+            .bag
+            .iter()
+            .map(|mino| MinoShape::from(i32::from(*mino)))
+            .collect();
+    }
+
+    // 이어하기용 스냅샷 생성. 상태를 내보낼 수 없는 난수 생성기이거나 줄 삭제 연출 중이라면 None
+    pub fn save_state(&self) -> Option<GameSnapshot> {
+        if self.is_clearing() {
+            return None;
+        }
+
+        Some(GameSnapshot {
+            version: GAME_SNAPSHOT_VERSION,
+            tetris_board: self.tetris_board.clone(),
+            record: self.record.clone(),
+            level: self.level,
+            lines_cleared_total: self.lines_cleared_total,
+            combo: self.combo,
+            back2back: self.back2back,
+            running_time: self.running_time,
+            current_mino: self.current_mino,
+            current_position: self.current_position,
+            hold: self.hold,
+            hold_used: self.hold_used,
+            bag: self.bag.iter().copied().collect(),
+            rng_state: self.rng.export_state()?,
+            rng_seed: self.rng_seed,
+            logic_time: self.logic_time,
+            gravity_elapsed: self.gravity_elapsed,
+            freezed: self.freezed,
+            lock_delay_count: self.lock_delay_count,
+            former_lock_delay_count: self.former_lock_delay_count,
+            last_chance_used: self.last_chance_used,
+            garbage_rng_state: self.garbage_rng.export_state()?,
+            garbage_elapsed: self.garbage_elapsed,
+            garbage_interval: self.garbage_interval,
+            pending_garbage: self.pending_garbage.iter().copied().collect(),
+        })
+    }
+
+    // 스냅샷으로 게임 상태를 되돌림. 지원하지 않는 버전이면 아무것도 바꾸지 않고 에러
+    // 누르고 있던 키, 처리하지 못한 입력, 입력 기록은 이어지지 않음
+    pub fn load_state(&mut self, snapshot: &GameSnapshot) -> Result<(), TetrisError> {
+        if snapshot.version != GAME_SNAPSHOT_VERSION {
+            return Err(TetrisError::ParseError(format!(
+                "unsupported snapshot version: {}",
+                snapshot.version
+            )));
         }
+
+        self.tetris_board = snapshot.tetris_board.clone();
+        // 고정 시점은 저장하지 않으므로 복원한 블럭은 다시 보이게 둠
+        self.cell_times = CellTimes::new(
+            self.tetris_board.column_count,
+            self.tetris_board.cells.len() as u32,
+        );
+        self.record = snapshot.record.clone();
+        self.level = snapshot.level;
+        self.lines_cleared_total = snapshot.lines_cleared_total;
+        self.combo = snapshot.combo;
+        self.back2back = snapshot.back2back;
+        self.message = None;
+        self.running_time = snapshot.running_time;
+        // 시간을 재고 있었다면 복원한 시점부터 다시 잼
+        self.timer_resumed_at = self.timer_resumed_at.map(|_| Instant::now());
+
+        self.current_mino = snapshot.current_mino;
+        self.current_position = snapshot.current_position;
+        self.hold = snapshot.hold;
+        self.hold_used = snapshot.hold_used;
+        self.bag = snapshot.bag.iter().copied().collect();
+        self.rng = Box::new(XorShiftRng::new(snapshot.rng_state));
+        self.rng_seed = snapshot.rng_seed;
+
+        self.logic_time = snapshot.logic_time;
+        self.gravity_elapsed = snapshot.gravity_elapsed;
+        self.freezed = snapshot.freezed;
+        self.lock_delay_count = snapshot.lock_delay_count;
+        self.former_lock_delay_count = snapshot.former_lock_delay_count;
+        self.last_chance_used = snapshot.last_chance_used;
+        self.garbage_rng = XorShiftRng::new(snapshot.garbage_rng_state);
+        self.garbage_elapsed = snapshot.garbage_elapsed;
+        self.garbage_interval = snapshot.garbage_interval;
+        self.pending_garbage = snapshot.pending_garbage.iter().copied().collect();
+
+        self.in_spin = SpinType::None;
+        self.last_action_was_rotation = false;
+        self.auto_shift = None;
+        self.pending_events.clear();
+        self.input_log.clear();
+
+        self.set_tick_interval(level_tick_interval(self.level));
+        self.dirty = true;
+
+        Ok(())
+    }
+
+    // 앞으로 나올 미노를 count개 미리 확인. 만들어둔 개수보다 많이 요청하면 None
+    pub fn peek_next(&self, count: usize) -> Option<Vec<MinoShape>> {
+        if count > self.bag.len() {
+            return None;
+        }
+
+        Some(self.bag.iter().take(count).copied().collect())
+    }
+
+    // 화면에 보여줄 넥스트 목록
+    pub fn next_preview(&self) -> Vec<MinoShape> {
+        self.bag
+            .iter()
+            .take(self.next_preview_count)
+            .copied()
+            .collect()
     }
 
     // 현재 가방 채움
-    fn fill_bag(&mut self) -> Option<()> {
+    fn fill_bag(&mut self) -> Result<(), TetrisError> {
+        if self.mino_list.is_empty() {
+            return Err(TetrisError::EmptyPieceList);
+        }
+
         match self.bag_mode {
-            BagType::SevenBag => {
-                let mut new_bag = random::shuffle(&self.mino_list).collect();
+            // 7종이 모두 있을 때만 7가방 규칙 적용. 연습용 부분 목록은 균등 랜덤으로 대체
+            BagType::SevenBag if self.mino_list.len() >= 7 => {
+                let mut new_bag = random::shuffle(self.rng.as_mut(), &self.mino_list).collect();
+                self.bag.append(&mut new_bag);
+            }
+            BagType::FourteenBag if self.mino_list.len() >= 7 => {
+                let double_list = [self.mino_list.as_slice(), self.mino_list.as_slice()].concat();
+                let mut new_bag = random::shuffle(self.rng.as_mut(), &double_list).collect();
                 self.bag.append(&mut new_bag);
             }
-            BagType::NoBag => {
+            // 가방 뒤쪽이 가장 최근에 만든 미노이므로 그대로 기록으로 씀 (저장/복원해도 같은 기록이 이어짐)
+            BagType::Classic => {
+                for _ in 0..self.mino_list.len() {
+                    let history: Vec<_> = self
+                        .bag
+                        .iter()
+                        .rev()
+                        .take(CLASSIC_HISTORY_SIZE)
+                        .map(|mino_shape| mino_shape.mino)
+                        .collect();
+
+                    let mino = random::select_with_history(
+                        self.rng.as_mut(),
+                        &self.mino_list,
+                        CLASSIC_ROLL_COUNT,
+                        |mino_shape| history.contains(&mino_shape.mino),
+                    );
+                    self.bag.push_back(mino);
+                }
+            }
+            _ => {
                 let mut new_bag = (0..self.mino_list.len())
-                    .map(|_| random::random_select(&self.mino_list))
+                    .map(|_| random::random_select(self.rng.as_mut(), &self.mino_list))
                     .collect();
                 self.bag.append(&mut new_bag);
             }
         }
 
-        Some(())
+        Ok(())
     }
 
     // 지울 줄이 있을 경우 줄을 지움
     fn clear_line(&mut self) -> ClearInfo {
-        let mut line = 0;
-        // 스핀 여부 반환
-        // 지운 줄 수 반환
-        for y in (0..self.tetris_board.row_count).into_iter() {
-            let row: &Vec<TetrisCell> = &self.tetris_board.cells[y as usize];
-
-            if row.iter().all(|e| e != &TetrisCell::Empty) {
-                line += 1;
-                for e in (0..=y).into_iter().rev() {
-                    if e == 0 {
-                        for cell in &mut self.tetris_board.cells[e as usize] {
-                            *cell = TetrisCell::Empty
-                        }
-                    } else {
-                        self.tetris_board.cells[e as usize] =
-                            self.tetris_board.cells[(e - 1) as usize].clone()
-                    }
-                }
-            }
-        }
+        // 회전 후 이동했다면 스핀 위치라도 스핀으로 치지 않음
+        let in_spin = if self.last_action_was_rotation {
+            self.in_spin.clone()
+        } else {
+            SpinType::None
+        };
+        // 지운 줄 수 반환. 칸별 고정 시점도 같은 줄을 지워서 보드와 맞춤
+        let full_rows = self.tetris_board.full_rows();
+        let line = self.tetris_board.clear_lines();
+        self.cell_times.clear_rows(&full_rows);
 
-        let is_perfect = self.tetris_board.unfold().iter().all(|e| e == &0);
+        // 줄을 지워서 보드가 완전히 비었다면 퍼펙트 클리어
+        let is_perfect = line > 0 && self.tetris_board.is_empty();
 
         if line > 0 {
+            self.record.line += line as u32;
+            self.level_up(line);
+
             let mut is_back2back = false;
 
             match self.combo {
                 Some(combo) => {
                     self.combo = Some(combo + 1);
-
-                    match line {
-                        1..=3 => {
-                            self.message = None;
-                        }
-                        4 => {
-                            self.message = Some("Quad".into());
-                        }
-                        _ => {}
-                    }
+                    self.record.max_combo = self.record.max_combo.max(combo + 1);
                 }
                 None => {
                     self.combo = Some(0);
+                }
+            }
 
-                    match line {
-                        1..=3 => {
-                            self.message = None;
-                        }
-                        4 => {
-                            self.message = Some("Quad".into());
-                            self.record.quad += 1;
-                            is_back2back = true
-                        }
-                        _ => {}
-                    }
+            match line {
+                1..=3 => {
+                    self.message = None;
                 }
+                4 => {
+                    self.message = Some("Quad".into());
+                    self.record.quad += 1;
+                    is_back2back = true
+                }
+                _ => {}
             }
 
-            match self.in_spin.clone() {
+            match in_spin.clone() {
                 SpinType::TSpin => {
                     is_back2back = true;
 
                     match line {
-                        1 => self.message = Some("T-Spin Single".into()),
-                        2 => self.message = Some("T-Spin Double".into()),
-                        3 => self.message = Some("T-Spin Triple".into()),
+                        1 => {
+                            self.message = Some("T-Spin Single".into());
+                            self.record.tspin_single += 1;
+                        }
+                        2 => {
+                            self.message = Some("T-Spin Double".into());
+                            self.record.tspin_double += 1;
+                        }
+                        3 => {
+                            self.message = Some("T-Spin Triple".into());
+                            self.record.tspin_triple += 1;
+                        }
                         _ => {}
                     }
                 }
@@ -234,6 +578,7 @@ impl GameInfo {
                 match self.back2back {
                     Some(back2back) => {
                         self.back2back = Some(back2back + 1);
+                        self.record.back_to_back = self.record.back_to_back.max(back2back + 1);
                     }
                     None => {
                         self.back2back = Some(0);
@@ -245,19 +590,19 @@ impl GameInfo {
 
             if is_perfect {
                 self.record.perfect_clear += 1;
-                self.message = Some("Perfect Clear".into())
+                self.message = Some("Perfect Clear".into());
+
+                if let Some(line_clear) = LineClear::from_lines(line) {
+                    self.record.score += line_clear.perfect_clear_score() * self.level as u64;
+                }
             }
         } else {
             self.combo = None;
         }
 
-        let score = calculate_score(
-            line,
-            is_perfect,
-            self.combo,
-            self.in_spin.clone(),
-            self.back2back,
-        );
+        self.add_line_score(line, &in_spin);
+
+        let score = calculate_score(line, self.combo, in_spin, self.level);
         self.record.score += score;
 
         self.after_clear();
@@ -275,352 +620,2884 @@ impl GameInfo {
             // 블럭 고정 후 현재 미노에서 제거
             self.tetris_board
                 .write_current_mino(current_mino.cells, self.current_position);
+            self.cell_times
+                .stamp(&current_mino.cells, self.current_position, self.logic_time);
             self.current_mino = None;
+            self.freezed = false;
             self.lock_delay_count = 0;
+            self.last_chance_used = false;
 
             self.hold_used = false;
         }
     }
 
-    // clear 처리 후에 트리거 (줄이 지워지는지 여부와 별개)
-    fn after_clear(&mut self) {
-        self.in_spin = SpinType::None;
-    }
-
-    // 한칸 내려간 후에 트리거
-    fn after_down(&mut self) {
-        self.in_spin = SpinType::None;
-    }
+    // 현재 미노가 전부 숨겨진 행 안에 있는지 여부 (락아웃)
+    fn is_lock_out(&self) -> bool {
+        match self.current_mino {
+            Some(current_mino) => {
+                let hidden_row_count = self.tetris_board.hidden_row_count as i64;
 
-    // 한칸씩 아래로 내려가는 중력 동작
-    pub fn tick(&mut self) {
-        if !self.on_play {
-            return;
+                current_mino
+                    .occupied_points(self.current_position)
+                    .iter()
+                    .all(|point| point.y < hidden_row_count)
+            }
+            None => false,
         }
+    }
 
-        let current_mino = self.current_mino;
-
-        match current_mino {
-            Some(current_mino) => {
-                let current_position = self.current_position;
-                let next_position = current_position.add_y(1);
+    // 현재 위치보다 아래에서 미노가 들어갈 수 있는 가장 가까운 위치. 없다면 None
+    // 락아웃은 바로 아래가 막혀서 고정될 때만 일어나므로, 쌓인 블럭 아래의 빈 공간까지 찾음
+    fn get_last_chance_position(&self) -> Option<Point> {
+        let current_mino = self.current_mino?;
+        let row_count = self.tetris_board.row_count as i64;
+
+        (self.current_position.y + 1..row_count)
+            .map(|y| Point {
+                x: self.current_position.x,
+                y,
+            })
+            .find(|position| valid_mino(&self.tetris_board, &current_mino.cells, *position))
+    }
 
-                if !valid_mino(&self.tetris_board, &current_mino.cells, next_position) {
-                    self.fix_current_mino();
-                    self.clear_line();
-                } else {
-                    self.current_position = next_position;
-                    self.after_down();
-                }
-            }
-            None => {
-                let mino = self.get_mino();
-                self.current_mino = Some(mino);
+    // 지운 줄 수를 누적하고 레벨이 올랐다면 중력 속도를 갱신
+    fn level_up(&mut self, line: u8) {
+        self.lines_cleared_total += line as u32;
 
-                let point = Point::start_point(self.tetris_board.column_count);
-                self.current_position = point;
+        let level = 1 + self.lines_cleared_total / LINES_PER_LEVEL;
 
-                if !valid_mino(&self.tetris_board, &mino.cells, point) {
-                    // 패배 처리
-                    self.game_over();
-                }
-            }
+        if level != self.level {
+            self.level = level;
+            self.set_tick_interval(level_tick_interval(level));
         }
     }
 
-    // 왼쪽 이동
-    pub fn left_move(&mut self) {
-        if let Some(current_mino) = self.current_mino {
-            let next_position = self.current_position.clone().add_x(-1);
+    // 지운 줄 점수에 현재 레벨을 곱해서 더함
+    // 스핀 없이 지웠다면 싱글/더블/트리플/테트리스 100/300/500/800점, T스핀이라면 tspin_base_score
+    // 일반 스핀은 calculate_score의 보너스만 받음. 백투백 상태를 갱신한 뒤에 호출해야 함
+    pub fn add_line_score(&mut self, line: u8, spin_type: &SpinType) -> u64 {
+        let base_score = match spin_type {
+            SpinType::None => LineClear::from_lines(line)
+                .map(|line_clear| line_clear.base_score())
+                .unwrap_or(0),
+            SpinType::TSpin | SpinType::Mini => tspin_base_score(spin_type, line),
+            SpinType::Spin => 0,
+        };
+        let mut score = base_score * self.level as u64;
 
-            if valid_mino(&self.tetris_board, &current_mino.cells, next_position) {
-                self.current_position = next_position;
-                if !valid_mino(&self.tetris_board, &current_mino.cells, self.current_position.add_y(1)) { 
-                    self.lock_delay_count += 1;
-                }
-            }
+        // 테트리스나 T스핀으로 연속해서 지웠다면 (백투백) 1.5배
+        if line > 0 && self.back2back.unwrap_or(0) > 0 {
+            score = score * 3 / 2;
         }
+
+        self.record.score += score;
+
+        score
     }
 
-    // 오른쪽 이동
-    pub fn right_move(&mut self) {
-        if let Some(current_mino) = self.current_mino {
-            let next_position = self.current_position.clone().add_x(1);
+    // 치즈 레이스라면 바닥에 구멍 하나씩 뚫린 쓰레기 줄을 깔아둠
+    fn fill_cheese(&mut self) {
+        if let Some(cheese_race) = self.cheese_race {
+            let mut rng = XorShiftRng::new(cheese_race.seed);
+            let column_count = self.tetris_board.column_count.max(1);
 
-            if valid_mino(&self.tetris_board, &current_mino.cells, next_position) {
-                self.current_position = next_position; 
-                if !valid_mino(&self.tetris_board, &current_mino.cells, self.current_position.add_y(1)) { 
-                    self.lock_delay_count += 1;
-                }
+            for _ in 0..cheese_race.garbage_rows {
+                let hole_column = (rng.next_u32() % column_count) as usize;
+                self.tetris_board.add_garbage(1, hole_column);
+                self.cell_times.push_up(1, self.tetris_board.row_count);
             }
         }
     }
 
-    // 왼쪽 회전 (반시계방향)
-    pub fn left_rotate(&mut self) {
-        if let Some(current_mino) = &mut self.current_mino {
-            if current_mino.mino == Mino::O {
-                return;
+    // 현재 미노를 고정하고 줄 삭제까지 처리. 락아웃이면 게임오버
+    fn lock_current_mino(&mut self) {
+        if self.is_lock_out() {
+            // 관대한 설정이면 미노당 한번, 더 아래 유효한 위치로 옮겨서 계속 진행
+            if self.lock_out_leniency && !self.last_chance_used {
+                if let Some(position) = self.get_last_chance_position() {
+                    self.last_chance_used = true;
+                    self.current_position = position;
+                    return;
+                }
             }
-            let real_length = if current_mino.mino == Mino::I { 4 } else { 3 };
-            let mut next_shape = current_mino.cells.clone();
 
-            rotate_left(&mut next_shape, real_length);
-            if valid_mino(&self.tetris_board, &next_shape, self.current_position) {
-                current_mino.rotation_count = (current_mino.rotation_count + 3) % 4;
-                current_mino.cells = next_shape; 
-                if !valid_mino(&self.tetris_board, &current_mino.cells, self.current_position.add_y(1)){ 
-                    self.lock_delay_count += 1;
-                }
+            self.game_over(TopOut::LockOut);
+            return;
+        }
 
-                if current_mino.mino == Mino::T {
-                    self.in_spin =
-                        valid_tspin(&self.tetris_board, &current_mino, self.current_position, 0);
-                }
-            } else {
-                for i in 0..4 {
-                    let mut next_position = self.current_position.clone();
-                    if real_length == 3 {
-                        next_position = next_position.move_xy(
-                            KICK_INDEX_3BY3[4 + current_mino.rotation_count][i][0],
-                            -KICK_INDEX_3BY3[4 + current_mino.rotation_count][i][1],
-                        ); // 4, 5, 6, 7 => 03, 10, 21, 32
-                    } else if real_length == 4 {
-                        next_position = next_position.move_xy(
-                            KICK_INDEX_I[4 + current_mino.rotation_count][i][0],
-                            -KICK_INDEX_I[4 + current_mino.rotation_count][i][1],
-                        );
-                    }
-                    if valid_mino(&self.tetris_board, &next_shape, next_position) {
-                        current_mino.rotation_count = (current_mino.rotation_count + 3) % 4;
-                        self.current_position = next_position;
-                        current_mino.cells = next_shape;
-                        if !valid_mino(&self.tetris_board, &current_mino.cells, self.current_position.add_y(1)){ 
-                            self.lock_delay_count += 1;
-                        }
-                
+        self.judge_finesse();
+        self.fix_current_mino();
 
-                        if current_mino.mino == Mino::T {
-                            self.in_spin =
-                                valid_tspin(&self.tetris_board, &current_mino, next_position, i);
-                        }
+        // 줄 삭제 연출이 켜져있다면 지울 줄을 표시만 해두고, 연출이 끝난 뒤 지움
+        if self.line_clear_delay > 0 {
+            let full_rows = self.tetris_board.full_rows();
 
-                        break;
-                    }
-                }
+            if !full_rows.is_empty() {
+                self.clearing_rows = full_rows;
+                self.clear_elapsed = 0;
+                return;
             }
         }
-    }
 
-    // 오른쪽 회전 (시계방향)
-    pub fn right_rotate(&mut self) {
-        if let Some(current_mino) = &mut self.current_mino {
-            if current_mino.mino == Mino::O {
-                return;
-            }
+        self.finish_lock();
+    }
 
-            let real_length = if current_mino.mino == Mino::I { 4 } else { 3 };
+    // 고정되는 미노에 누른 키가 빈 보드 기준 최소 입력 수보다 많았다면 피네스 실수로 기록
+    fn judge_finesse(&mut self) {
+        let current_mino = match self.current_mino {
+            Some(current_mino) => current_mino,
+            None => return,
+        };
 
-            let mut next_shape = current_mino.cells.clone();
-            rotate_right(&mut next_shape, real_length);
-            if valid_mino(&self.tetris_board, &next_shape, self.current_position) {
-                current_mino.rotation_count = (current_mino.rotation_count + 1) % 4;
-                current_mino.cells = next_shape;
-                if !valid_mino(&self.tetris_board, &current_mino.cells, self.current_position.add_y(1)){ 
-                    self.lock_delay_count += 1;
-                }
+        if self.piece_soft_dropped {
+            return;
+        }
 
-                if current_mino.mino == Mino::T {
-                    self.in_spin =
-                        valid_tspin(&self.tetris_board, &current_mino, self.current_position, 0);
-                }
-            } else {
-                for i in 0..4 {
-                    let mut next_position = self.current_position.clone();
-                    if real_length == 3 {
-                        next_position = next_position.move_xy(
-                            KICK_INDEX_3BY3[0 + current_mino.rotation_count][i][0],
-                            -KICK_INDEX_3BY3[0 + current_mino.rotation_count][i][1],
-                        ); // 0, 1, 2, 3 => 01, 12, 23, 30
-                    } else if real_length == 4 {
-                        next_position = next_position.move_xy(
-                            KICK_INDEX_I[0 + current_mino.rotation_count][i][0],
-                            -KICK_INDEX_I[0 + current_mino.rotation_count][i][1],
-                        );
-                    }
-                    if valid_mino(&self.tetris_board, &next_shape, next_position) {
-                        current_mino.rotation_count = (current_mino.rotation_count + 1) % 4;
-                        self.current_position = next_position;
-                        current_mino.cells = next_shape;
-                        if !valid_mino(&self.tetris_board, &current_mino.cells, self.current_position.add_y(1)){ 
-                            self.lock_delay_count += 1;
-                        }
-                        if current_mino.mino == Mino::T {
-                            self.in_spin =
-                                valid_tspin(&self.tetris_board, &current_mino, next_position, i);
-                        }
+        let minimal = minimal_input_count(
+            &current_mino,
+            self.current_position.x,
+            self.tetris_board.column_count,
+            self.spawn_column_offset,
+        );
 
-                        break;
-                    }
-                }
+        if let Some(minimal) = minimal {
+            if self.piece_input_count > minimal {
+                self.record.finesse_fault += 1;
             }
         }
     }
 
-    // 소프트드랍
-    pub fn soft_drop(&mut self) {
-        self.tick();
+    // 줄 삭제 연출 중인지 여부
+    pub fn is_clearing(&self) -> bool {
+        !self.clearing_rows.is_empty()
     }
 
-    // 하드드랍될 위치 획득
-    pub fn get_hard_drop_position(&self) -> Option<Point> {
-        match self.current_mino {
-            Some(current_mino) => {
-                let current_position = self.current_position;
-                let mut next_position = current_position.add_y(1);
-                loop {
-                    if !valid_mino(&self.tetris_board, &current_mino.cells, next_position) {
-                        next_position = next_position.add_y(-1);
-                        break;
-                    } else {
-                        next_position = next_position.add_y(1);
-                    }
-                }
+    // 고정된 미노로 가득 찬 줄을 지우고 점수, 속도, 승리 조건 갱신
+    fn finish_lock(&mut self) {
+        self.clearing_rows.clear();
+        self.clear_elapsed = 0;
 
-                Some(next_position)
-            }
-            None => None,
-        }
-    }
+        let clear_info = self.clear_line();
+        self.update_tick_interval();
 
-    // 하드드랍 동작
-    pub fn hard_drop(&mut self) {
-        let position = self.get_hard_drop_position();
+        // 치즈 레이스에서 쓰레기 줄이 모두 지워졌다면 승리
+        if self.cheese_race.is_some()
+            && clear_info.line > 0
+            && self.tetris_board.garbage_row_count() == 0
+        {
+            self.game_clear();
+            return;
+        }
 
-        match position {
-            Some(position) => {
-                self.current_position = position;
+        // 떨어지는 중인 미노가 없는 미노 사이에만 받아둔 쓰레기 줄을 올림
+        self.apply_pending_garbage();
+        if self.lose {
+            return;
+        }
 
-                self.fix_current_mino();
+        // 즉시 재출현 설정이면 줄이 지워진 직후 다음 틱을 기다리지 않고 바로 새 미노를 꺼냄
+        if clear_info.line > 0 && self.instant_respawn {
+            self.spawn_mino();
+        }
+    }
 
-                self.clear_line();
+    // 현재 미노가 바닥이나 쌓인 블럭에 닿아 한칸 아래로 내려갈 수 없는지 여부. 현재 미노가 없으면 false
+    pub fn is_grounded(&self) -> bool {
+        match self.current_mino {
+            Some(current_mino) => !valid_mino(
+                &self.tetris_board,
+                &current_mino.cells,
+                self.current_position.add_y(1),
+            ),
+            None => false,
+        }
+    }
+
+    // 이동/회전 후 고정 대기 상태 갱신. 바닥에 닿아있다면 록딜레이 카운트 증가
+    fn refresh_grounded(&mut self) {
+        self.freezed = self.is_grounded();
+
+        if self.freezed {
+            self.lock_delay_count = self.lock_delay_count.saturating_add(1);
+        }
+    }
+
+    // 기준 틱 간격 변경. 중력은 매 단계마다 간격을 다시 읽으므로 진행 중에도 바로 반영됨
+    pub fn set_tick_interval(&mut self, interval: u64) {
+        self.base_tick_interval = interval.max(MIN_TICK_INTERVAL);
+        self.update_tick_interval();
+    }
+
+    // 렌더링 최소 간격 변경. 0이면 매 애니메이션 프레임마다 그림. 렌더링 루프가 매 프레임 다시 읽음
+    pub fn set_render_interval(&mut self, interval: u64) {
+        self.render_interval = interval;
+    }
+
+    // 화면에 보여줄 넥스트 개수 변경. 0이면 넥스트 칸을 비워둠
+    // 가방에 미리 만들어둘 개수도 그 이상으로 늘려서 가방 경계에서도 넥스트가 끊기지 않게 함
+    pub fn set_next_preview_count(&mut self, count: usize) {
+        self.next_preview_count = count;
+        self.next_buffer_count = self.next_buffer_count.max(count);
+        self.manage_bag();
+        self.dirty = true;
+    }
+
+    // 출현할 미노 목록 변경 (연습 모드). 빈 목록이면 기본 7종
+    // 이미 만들어둔 가방은 이전 목록으로 채워졌으므로 비우고 새 목록으로 다시 채움. 떨어지는 중인 미노는 그대로 둠
+    pub fn set_mino_list(&mut self, mino_list: Vec<MinoShape>) {
+        self.mino_list = if mino_list.is_empty() {
+            MinoShape::all()
+        } else {
+            mino_list
+        };
+        self.bag = VecDeque::new();
+        self.manage_bag();
+        self.dirty = true;
+    }
+
+    // 보드 상태에 맞춰 틱 간격 갱신
+    pub fn update_tick_interval(&mut self) {
+        self.tick_interval = match self.adaptive_speed {
+            Some(adaptive_speed) => adaptive_speed
+                .tick_interval(self.base_tick_interval, self.tetris_board.fill_ratio()),
+            None => self.base_tick_interval,
+        };
+    }
+
+    // clear 처리 후에 트리거 (줄이 지워지는지 여부와 별개)
+    fn after_clear(&mut self) {
+        self.in_spin = SpinType::None;
+        self.last_action_was_rotation = false;
+    }
+
+    // 한칸 내려간 후에 트리거
+    fn after_down(&mut self) {
+        self.in_spin = SpinType::None;
+        self.last_action_was_rotation = false;
+    }
+
+    // 좌우로 한칸 이동한 후에 트리거
+    fn after_move(&mut self) {
+        self.in_spin = SpinType::None;
+        self.last_action_was_rotation = false;
+    }
+
+    // 한칸씩 아래로 내려가는 중력 동작
+    pub fn tick(&mut self) {
+        if !self.on_play || self.is_clearing() {
+            return;
+        }
 
-                self.tick();
+        match self.current_mino {
+            Some(_) => {
+                if self.is_grounded() {
+                    // 고정 대기 상태가 아니었다면 대기 상태로만 전환하고, 록딜레이가 지난 다음 틱에 고정
+                    // 무한 록딜레이 규칙이면 중력으로는 고정하지 않음 (하드드랍으로만 고정)
+                    if self.freezed {
+                        if self.lock_delay_policy != LockDelayPolicy::Infinite {
+                            self.lock_current_mino();
+                        }
+                    } else {
+                        self.freezed = true;
+                    }
+                } else if self.move_down() && self.soft_dropping {
+                    // 소프트드랍으로 내려간 칸만 한칸당 1점
+                    self.record.score += 1;
+                }
+            }
+            None => {
+                self.spawn_mino();
             }
-            None => {}
         }
     }
 
-    // 미노 홀드
-    pub fn hold(&mut self) {
-        if !self.hold_used {
-            match self.hold {
-                Some(hold) => {
-                    let temp = self.current_mino;
-                    self.current_mino = Some(hold);
-                    self.hold = temp;
+    // 현재 미노를 한칸 아래로 이동. 더 내려갈 수 없다면 고정 대기 상태로 전환하고 false 반환
+    // 바로 고정하지 않으므로 록딜레이 동안 좌우 이동이나 회전으로 자리를 옮길 수 있음
+    // 보드에는 고정된 블럭만 기록하고 현재 미노는 렌더링할 때 덧그리므로 이전 위치를 지울 필요는 없음
+    pub fn move_down(&mut self) -> bool {
+        if self.current_mino.is_none() {
+            return false;
+        }
+
+        if self.is_grounded() {
+            self.freezed = true;
+            return false;
+        }
+
+        self.current_position = self.current_position.add_y(1);
+        // 이번 이동으로 바닥에 닿았다면 지금부터 록딜레이를 셈
+        self.freezed = self.is_grounded();
+        if self.freezed {
+            self.gravity_elapsed = 0;
+        }
+        self.after_down();
+
+        true
+    }
+
+    // 가방에서 새 미노를 꺼내 출현 위치에 배치
+    fn spawn_mino(&mut self) {
+        if !self.on_play {
+            return;
+        }
+
+        let mino = self.get_mino();
+        self.record.count_spawn(mino.mino);
+        self.place_at_spawn(mino);
+    }
+
+    // 미노를 출현 위치에 배치. 출현 위치에 들어갈 수 없다면 게임오버
+    fn place_at_spawn(&mut self, mino: MinoShape) {
+        self.current_mino = Some(mino);
+        self.piece_input_count = 0;
+        self.piece_soft_dropped = false;
+
+        let point = Point::start_point(
+            self.tetris_board.column_count,
+            self.spawn_row,
+            self.spawn_column_offset,
+        );
+        self.current_position = point;
+
+        if !valid_mino(&self.tetris_board, &mino.cells, point) {
+            // 패배 처리
+            self.game_over(TopOut::BlockOut);
+        } else {
+            self.apply_wall_charge();
+        }
+    }
+
+    // 다음 중력 틱까지 기다려야 하는 시간. 바닥에 닿아 고정 대기중이면 록딜레이가 지난 뒤 고정
+    fn gravity_delay(&self) -> u64 {
+        if self.freezed {
+            self.lock_delay as u64
+        } else if self.soft_dropping {
+            (self.tick_interval / self.sdf.max(1) as u64).max(1)
+        } else {
+            self.tick_interval
+        }
+    }
+
+    // 다음 쓰레기 줄이 올라오기까지 남은 시간. 챌린지가 꺼져있으면 None
+    fn time_until_garbage(&self) -> Option<u64> {
+        self.garbage_challenge?;
+
+        Some(self.garbage_interval.saturating_sub(self.garbage_elapsed))
+    }
+
+    // 줄 삭제 연출이 끝나기까지 남은 시간. 연출 중이 아니면 None
+    fn time_until_clear_end(&self) -> Option<u64> {
+        if !self.is_clearing() {
+            return None;
+        }
+
+        Some((self.line_clear_delay as u64).saturating_sub(self.clear_elapsed))
+    }
+
+    // 다음 자동 이동이 일어나기까지 남은 시간. 자동 이동이 없으면 None
+    fn time_until_auto_shift(&self) -> Option<u64> {
+        let auto_shift = self.auto_shift?;
+
+        if !auto_shift.is_charged(self.das) {
+            Some((self.das - auto_shift.held_time) as u64)
+        } else if self.arr > 0 {
+            Some((self.arr - auto_shift.repeat_time) as u64)
+        } else {
+            None
+        }
+    }
+
+    // 게임 시간을 elapsed(밀리초)만큼 진행. 중력 틱과 DAS/ARR 이동이 일어나는 시점마다 끊어서 처리하므로
+    // 몇번에 나눠서 호출하든 같은 시간만큼 진행하면 같은 결과가 나옴
+    pub fn advance(&mut self, elapsed: u64) {
+        if self.paused {
+            return;
+        }
+
+        // 시간을 진행하기 전에 이번 프레임에 쌓인 입력부터 처리
+        self.flush_events();
+
+        let mut remaining = elapsed;
+        let start_time = self.logic_time;
+
+        while remaining > 0 && self.on_play {
+            // 이동/회전으로 록딜레이 카운트가 올라갔다면 규칙에 따라 고정까지의 시간을 다시 셈
+            if self.former_lock_delay_count != self.lock_delay_count {
+                let reset = match self.lock_delay_policy {
+                    LockDelayPolicy::StepReset => false,
+                    LockDelayPolicy::MoveReset { max_reset } => self.lock_delay_count <= max_reset,
+                    LockDelayPolicy::Infinite => true,
+                };
+
+                if reset {
+                    self.gravity_elapsed = 0;
                 }
-                None => {
-                    self.hold = self.current_mino;
-                    self.current_mino = None;
-                    self.fill_bag();
+                self.former_lock_delay_count = self.lock_delay_count;
+            }
+
+            let until_tick = self.gravity_delay().saturating_sub(self.gravity_elapsed);
+            let step = [
+                Some(until_tick),
+                self.time_until_auto_shift(),
+                self.time_until_garbage(),
+                self.time_until_clear_end(),
+            ]
+            .into_iter()
+            .flatten()
+            .fold(remaining, u64::min)
+            .max(1);
+
+            self.logic_time += step;
+            remaining = remaining.saturating_sub(step);
+
+            // 좌우 이동키를 누르고 있다면 DAS/ARR 처리
+            if self.auto_shift.is_some() {
+                self.update_auto_shift(step as u32);
+                self.dirty = true;
+            }
+
+            if self.is_clearing() {
+                // 줄 삭제 연출 중에는 중력을 멈추고, 연출이 끝나면 줄을 지움
+                self.clear_elapsed += step;
+                self.dirty = true;
+
+                if self.clear_elapsed >= self.line_clear_delay as u64 {
+                    self.finish_lock();
+                }
+            } else {
+                self.gravity_elapsed += step;
+
+                // 지정된 딜레이만큼 지났다면 다시 초기화하고 tick 한칸 수행
+                if self.gravity_elapsed >= self.gravity_delay() {
+                    self.gravity_elapsed = 0;
+                    self.tick();
+                    self.dirty = true;
                 }
             }
 
-            self.hold_used = true;
+            // 챌린지 모드라면 간격마다 쓰레기 줄 추가
+            if self.garbage_challenge.is_some() {
+                self.garbage_elapsed += step;
 
-            self.tick();
+                if self.garbage_elapsed >= self.garbage_interval && self.on_play {
+                    self.garbage_elapsed = 0;
+                    self.add_random_garbage();
+                    self.dirty = true;
+                }
+            }
+        }
+
+        // 이번에 진행한 시간 사이에 사라진 블럭이 있다면 화면을 다시 그림
+        if let Some(fading_blocks) = self.fading_blocks {
+            if self.cell_times.any_faded_between(
+                start_time,
+                self.logic_time,
+                fading_blocks.fade_delay,
+            ) {
+                self.dirty = true;
+            }
         }
     }
 
-    // 180도 회전
-    pub fn double_rotate(&mut self) {
-        if let Some(current_mino) = &mut self.current_mino {
-            if current_mino.mino == Mino::O {
+    // 화면에 그릴 보드. 블럭이 사라지는 모드라면 사라진 칸을 빈 칸으로 바꿈 (로직상으로는 그대로 남아있음)
+    pub fn visible_board(&self) -> TetrisBoard {
+        let mut tetris_board = self.tetris_board.clone();
+
+        if let Some(fading_blocks) = self.fading_blocks {
+            for (y, row) in tetris_board.cells.iter_mut().enumerate() {
+                for (x, cell) in row.iter_mut().enumerate() {
+                    if self
+                        .cell_times
+                        .is_faded(x, y, self.logic_time, fading_blocks.fade_delay)
+                    {
+                        *cell = TetrisCell::Empty;
+                    }
+                }
+            }
+        }
+
+        tetris_board
+    }
+
+    // 챌린지 시드로 줄 수와 구멍 위치를 정해서 쓰레기 줄 추가. 이후 간격을 줄여 난이도를 올림
+    fn add_random_garbage(&mut self) {
+        let garbage_challenge = match self.garbage_challenge {
+            Some(garbage_challenge) => garbage_challenge,
+            None => return,
+        };
+
+        let max_lines = garbage_challenge.max_lines.max(1);
+        let lines = 1 + self.garbage_rng.next_u32() % max_lines;
+        let hole_column =
+            (self.garbage_rng.next_u32() % self.tetris_board.column_count.max(1)) as usize;
+
+        self.add_garbage(lines, hole_column);
+        self.garbage_interval = garbage_challenge.next_interval(self.garbage_interval);
+    }
+
+    // 상대에게 받은 쓰레기 줄을 쌓아둠. 바로 올리지 않고 다음 미노가 고정될 때 한꺼번에 올림
+    pub fn queue_garbage(&mut self, lines: u32, hole_column: usize) {
+        if lines > 0 {
+            self.pending_garbage.push_back((lines, hole_column));
+        }
+    }
+
+    // 아직 올라오지 않은 쓰레기 줄 수
+    pub fn pending_garbage_lines(&self) -> u32 {
+        self.pending_garbage.iter().map(|(lines, _)| lines).sum()
+    }
+
+    // 쌓아둔 쓰레기 줄을 받은 순서대로 올림. 도중에 게임오버가 되면 나머지는 버림
+    fn apply_pending_garbage(&mut self) {
+        while let Some((lines, hole_column)) = self.pending_garbage.pop_front() {
+            self.add_garbage(lines, hole_column);
+            self.dirty = true;
+
+            if self.lose {
+                self.pending_garbage.clear();
                 return;
             }
+        }
+    }
 
-            let real_length = if current_mino.mino == Mino::I { 4 } else { 3 };
+    // 바닥에서 쓰레기 줄을 밀어올림. 보드 위로 블럭이 밀려나면 게임오버
+    pub fn add_garbage(&mut self, lines: u32, hole_column: usize) {
+        let topped_out = self.tetris_board.add_garbage(lines, hole_column);
+        self.cell_times.push_up(lines, self.tetris_board.row_count);
 
-            let mut next_shape = current_mino.cells.clone();
-            rotate_right(&mut next_shape, real_length);
-            rotate_right(&mut next_shape, real_length);
+        if topped_out {
+            self.game_over(TopOut::PushOut);
+            return;
+        }
+
+        // 올라온 줄과 현재 미노가 겹치면 겹치지 않을 때까지 위로 올림
+        if let Some(current_mino) = self.current_mino {
+            for _ in 0..lines {
+                if valid_mino(
+                    &self.tetris_board,
+                    &current_mino.cells,
+                    self.current_position,
+                ) {
+                    break;
+                }
+                self.current_position = self.current_position.add_y(-1);
+            }
 
-            if valid_mino(
+            if !valid_mino(
                 &self.tetris_board,
                 &current_mino.cells,
                 self.current_position,
             ) {
-                current_mino.cells = next_shape;
+                self.game_over(TopOut::PushOut);
+                return;
             }
+
+            self.freezed = self.is_grounded();
         }
+
+        self.update_tick_interval();
     }
 
-    // 게임오버
-    fn game_over(&mut self) {
-        self.on_play = false;
-        self.lose = true;
-        self.current_mino = None;
-        write_text("message", "Game Over".into());
+    // 입력 이벤트를 다음 프레임에 처리하도록 쌓아둠. 일시정지 중이거나 게임이 끝났다면 무시
+    pub fn queue_event(&mut self, event: Event) {
+        if self.paused || !self.on_play {
+            return;
+        }
+
+        // 거울 모드에서는 화면 기준 입력을 게임 로직 기준으로 뒤집어서 쌓음 (입력 기록과 리플레이는 로직 기준)
+        let event = if self.mirror { event.mirrored() } else { event };
+
+        self.pending_events.push(event);
     }
 
-    // 보드 초기화
-    pub fn init_board(&mut self) -> Option<()> {
-        let column_count = self.tetris_board.column_count;
-        let row_count = self.tetris_board.row_count;
+    // 거울 모드 전환. 누르고 있던 좌우 이동키는 방향이 바뀌므로 해제
+    pub fn set_mirror(&mut self, mirror: bool) {
+        if self.mirror != mirror {
+            self.mirror = mirror;
+            self.auto_shift = None;
+            self.dirty = true;
+        }
+    }
 
-        self.tetris_board = TetrisBoard {
-            cells: vec![vec![TetrisCell::Empty; column_count as usize]; row_count as usize],
-            row_count,
-            column_count,
-            board_height: self.tetris_board.board_height,
-            board_width: self.tetris_board.board_width,
-            hidden_row_count: self.tetris_board.hidden_row_count,
+    // 쌓아둔 입력을 Event::priority 순서로 처리. 같은 프레임에 들어온 입력은 도착 순서와 관계없이 항상 같은 결과
+    pub fn flush_events(&mut self) {
+        let mut pending_events = std::mem::take(&mut self.pending_events);
+        pending_events.sort_by_key(|event| event.priority());
+
+        for event in pending_events {
+            self.apply_event(event);
+        }
+    }
+
+    // 입력 이벤트를 현재 게임 진행 시간과 함께 기록하고 처리
+    pub fn apply_event(&mut self, event: Event) {
+        self.dirty = true;
+        self.input_log.push(TimedEvent {
+            time: self.logic_time,
+            event,
+        });
+
+        match event {
+            Event::LeftMove | Event::RightMove => self.piece_input_count += 1,
+            Event::LeftRotate | Event::RightRotate | Event::DoubleRotate => {
+                self.piece_input_count += 1
+            }
+            Event::SoftDrop => self.piece_soft_dropped = true,
+            _ => {}
+        }
+
+        match event {
+            Event::LeftMove => self.press_shift(ShiftDirection::Left),
+            Event::RightMove => self.press_shift(ShiftDirection::Right),
+            Event::LeftRelease => self.release_shift(ShiftDirection::Left),
+            Event::RightRelease => self.release_shift(ShiftDirection::Right),
+            Event::LeftRotate => self.left_rotate(),
+            Event::RightRotate => self.right_rotate(),
+            Event::SoftDrop => self.soft_drop(),
+            Event::SoftDropRelease => self.soft_dropping = false,
+            Event::HardDrop => {
+                self.hard_drop();
+            }
+            Event::DoubleRotate => self.double_rotate(),
+            Event::Hold => self.hold(),
+        }
+    }
+
+    // 지금까지의 입력 기록으로 실제 시간 기준 리플레이 생성
+    pub fn realtime_replay(&self) -> RealtimeReplay {
+        RealtimeReplay {
+            seed: self.rng_seed,
+            events: self.input_log.clone(),
+            end_time: self.logic_time,
+            board_hash: self.tetris_board.board_hash(),
+            score: self.record.score,
+        }
+    }
+
+    // 왼쪽으로 한칸 이동. 이동했다면 true
+    pub fn move_left(&mut self) -> bool {
+        self.move_horizontal(-1)
+    }
+
+    // 오른쪽으로 한칸 이동. 이동했다면 true
+    pub fn move_right(&mut self) -> bool {
+        self.move_horizontal(1)
+    }
+
+    // 좌우로 dx칸 이동. 이동할 위치가 유효할 때만 반영
+    fn move_horizontal(&mut self, dx: i64) -> bool {
+        let current_mino = match self.current_mino {
+            Some(current_mino) => current_mino,
+            None => return false,
         };
 
-        Some(())
+        let next_position = self.current_position.add_x(dx);
+
+        if !valid_mino(&self.tetris_board, &current_mino.cells, next_position) {
+            return false;
+        }
+
+        self.current_position = next_position;
+        self.after_move();
+        // 바닥에 닿아있다면 고정 대기 상태 유지, 떨어질 수 있다면 해제
+        self.refresh_grounded();
+
+        true
     }
 
-    // 컨텍스트 초기화
-    pub fn init_context(&mut self) -> Option<()> {
-        self.back2back = None;
-        self.combo = None;
-        self.in_spin = SpinType::None;
-        self.message = None;
+    // 해당 방향으로 한칸 이동. 이동에 성공했는지 여부 반환
+    fn shift(&mut self, direction: ShiftDirection) -> bool {
+        match direction {
+            ShiftDirection::Left => self.move_left(),
+            ShiftDirection::Right => self.move_right(),
+        }
+    }
 
-        Some(())
+    // 해당 방향으로 더이상 갈 수 없을 때까지 이동
+    fn shift_to_wall(&mut self, direction: ShiftDirection) {
+        while self.shift(direction) {}
     }
 
-    // 가방 초기화
-    pub fn init_bag(&mut self) -> Option<()> {
-        self.bag = VecDeque::new();
-        self.current_mino = None;
-        self.hold_used = false;
-        self.hold = None;
+    // 좌우 이동키 누름. 한칸 이동 후 DAS 충전 시작
+    pub fn press_shift(&mut self, direction: ShiftDirection) {
+        self.shift(direction);
+        self.auto_shift = Some(AutoShift::new(direction));
+    }
 
-        Some(())
+    // 좌우 이동키 뗌
+    pub fn release_shift(&mut self, direction: ShiftDirection) {
+        if let Some(auto_shift) = self.auto_shift {
+            if auto_shift.direction == direction {
+                self.auto_shift = None;
+            }
+        }
     }
 
-    // 점수 초기화
-    pub fn init_score(&mut self) -> Option<()> {
-        self.record = Default::default();
+    // 키를 누르고 있는 동안 경과시간만큼 DAS를 충전하고, 충전됐다면 ARR 간격으로 반복 이동
+    pub fn update_auto_shift(&mut self, elapsed: u32) {
+        let das = self.das;
+        let arr = self.arr;
 
-        Some(())
+        let mut auto_shift = match self.auto_shift {
+            Some(auto_shift) => auto_shift,
+            None => return,
+        };
+
+        let was_charged = auto_shift.is_charged(das);
+        auto_shift.held_time = auto_shift.held_time.saturating_add(elapsed);
+
+        if !auto_shift.is_charged(das) {
+            self.auto_shift = Some(auto_shift);
+            return;
+        }
+
+        let direction = auto_shift.direction;
+        let mut shift_count = 0;
+
+        if !was_charged {
+            // 막 충전된 순간 한칸 이동
+            shift_count += 1;
+            auto_shift.repeat_time = auto_shift.held_time - das;
+        } else {
+            auto_shift.repeat_time += elapsed;
+        }
+
+        // ARR이 0이면 나눌 수 없으므로 아래에서 벽까지 한번에 이동
+        if let Some(repeat_count) = auto_shift.repeat_time.checked_div(arr) {
+            shift_count += repeat_count;
+            auto_shift.repeat_time %= arr;
+        }
+
+        self.auto_shift = Some(auto_shift);
+
+        if arr == 0 {
+            self.shift_to_wall(direction);
+        } else {
+            for _ in 0..shift_count {
+                self.shift(direction);
+            }
+        }
     }
 
-    pub fn init_running_time(&mut self) -> Option<()> {
-        self.running_time = 0;
-        Some(())
+    // 새 미노가 나왔을 때 DAS가 이미 충전되어 있다면 기다리지 않고 바로 밀어줌 (월 차지)
+    fn apply_wall_charge(&mut self) {
+        if let Some(auto_shift) = self.auto_shift {
+            if auto_shift.is_charged(self.das) {
+                if self.arr == 0 {
+                    self.shift_to_wall(auto_shift.direction);
+                } else {
+                    self.shift(auto_shift.direction);
+                }
+            }
+        }
     }
 
-    // 게임 초기화
-    pub fn init_game(&mut self) -> Option<()> {
-        self.init_bag()?;
-        self.init_board()?;
-        self.init_score()?;
-        self.init_context()?;
-        self.init_running_time()?;
+    // 현재 미노의 회전 상태 (0: 출현 상태, 1: 시계방향 90도, 2: 180도, 3: 반시계방향 90도). 현재 미노가 없으면 None
+    pub fn rotation_state(&self) -> Option<usize> {
+        self.current_mino
+            .map(|current_mino| current_mino.rotation_count)
+    }
 
-        Some(())
+    // 방향에 맞춰 SRS 회전 (벽차기 포함). O미노는 회전하지 않음. 회전했다면 true
+    pub fn rotate(&mut self, direction: RotateDirection) -> bool {
+        let former_state = self.rotation_state();
+
+        match direction {
+            RotateDirection::Clockwise => self.right_rotate(),
+            RotateDirection::CounterClockwise => self.left_rotate(),
+        }
+
+        former_state != self.rotation_state()
+    }
+
+    // 왼쪽 회전 (반시계방향)
+    pub fn left_rotate(&mut self) {
+        if let Some(current_mino) = &mut self.current_mino {
+            if current_mino.mino == Mino::O {
+                return;
+            }
+            let real_length = current_mino.rotation_length();
+            let mut next_shape = current_mino.cells.clone();
+
+            rotate_left(&mut next_shape, real_length);
+            if valid_mino(&self.tetris_board, &next_shape, self.current_position) {
+                current_mino.rotation_count = (current_mino.rotation_count + 3) % 4;
+                current_mino.cells = next_shape;
+                if current_mino.mino == Mino::T {
+                    self.in_spin =
+                        valid_tspin(&self.tetris_board, &current_mino, self.current_position, 0);
+                }
+
+                self.last_action_was_rotation = true;
+                // 바닥에 닿아있다면 고정 대기 상태 유지, 떨어질 수 있다면 해제
+                self.refresh_grounded();
+            } else {
+                for i in 0..4 {
+                    let mut next_position = self.current_position.clone();
+                    if real_length == 3 {
+                        next_position = next_position.move_xy(
+                            KICK_INDEX_3BY3[4 + current_mino.rotation_count][i][0],
+                            -KICK_INDEX_3BY3[4 + current_mino.rotation_count][i][1],
+                        ); // 4, 5, 6, 7 => 03, 10, 21, 32
+                    } else if real_length == 4 {
+                        next_position = next_position.move_xy(
+                            KICK_INDEX_I[4 + current_mino.rotation_count][i][0],
+                            -KICK_INDEX_I[4 + current_mino.rotation_count][i][1],
+                        );
+                    }
+                    if valid_mino(&self.tetris_board, &next_shape, next_position) {
+                        current_mino.rotation_count = (current_mino.rotation_count + 3) % 4;
+                        self.current_position = next_position;
+                        current_mino.cells = next_shape;
+                        if current_mino.mino == Mino::T {
+                            self.in_spin =
+                                valid_tspin(&self.tetris_board, &current_mino, next_position, i);
+                        }
+
+                        self.last_action_was_rotation = true;
+                        // 바닥에 닿아있다면 고정 대기 상태 유지, 떨어질 수 있다면 해제
+                        self.refresh_grounded();
+
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    // 오른쪽 회전 (시계방향)
+    pub fn right_rotate(&mut self) {
+        if let Some(current_mino) = &mut self.current_mino {
+            if current_mino.mino == Mino::O {
+                return;
+            }
+
+            let real_length = current_mino.rotation_length();
+
+            let mut next_shape = current_mino.cells.clone();
+            rotate_right(&mut next_shape, real_length);
+            if valid_mino(&self.tetris_board, &next_shape, self.current_position) {
+                current_mino.rotation_count = (current_mino.rotation_count + 1) % 4;
+                current_mino.cells = next_shape;
+                if current_mino.mino == Mino::T {
+                    self.in_spin =
+                        valid_tspin(&self.tetris_board, &current_mino, self.current_position, 0);
+                }
+
+                self.last_action_was_rotation = true;
+                // 바닥에 닿아있다면 고정 대기 상태 유지, 떨어질 수 있다면 해제
+                self.refresh_grounded();
+            } else {
+                for i in 0..4 {
+                    let mut next_position = self.current_position.clone();
+                    if real_length == 3 {
+                        next_position = next_position.move_xy(
+                            KICK_INDEX_3BY3[0 + current_mino.rotation_count][i][0],
+                            -KICK_INDEX_3BY3[0 + current_mino.rotation_count][i][1],
+                        ); // 0, 1, 2, 3 => 01, 12, 23, 30
+                    } else if real_length == 4 {
+                        next_position = next_position.move_xy(
+                            KICK_INDEX_I[0 + current_mino.rotation_count][i][0],
+                            -KICK_INDEX_I[0 + current_mino.rotation_count][i][1],
+                        );
+                    }
+                    if valid_mino(&self.tetris_board, &next_shape, next_position) {
+                        current_mino.rotation_count = (current_mino.rotation_count + 1) % 4;
+                        self.current_position = next_position;
+                        current_mino.cells = next_shape;
+                        if current_mino.mino == Mino::T {
+                            self.in_spin =
+                                valid_tspin(&self.tetris_board, &current_mino, next_position, i);
+                        }
+
+                        self.last_action_was_rotation = true;
+                        // 바닥에 닿아있다면 고정 대기 상태 유지, 떨어질 수 있다면 해제
+                        self.refresh_grounded();
+
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    // 소프트드랍 키 누름. 바로 한칸 내리고, 키를 떼기 전까지 중력이 sdf배 빨라짐
+    // 바닥에 닿아도 바로 고정하지 않고 록딜레이를 따름
+    pub fn soft_drop(&mut self) {
+        self.soft_dropping = true;
+
+        if self.move_down() {
+            self.record.score += 1;
+        }
+    }
+
+    // 자동 플레이 봇이 현재 미노를 놓을 자리와 입력. 현재 미노가 없으면 None
+    // 입력은 보드 기준이므로 apply_event로 넣어야 함 (queue_event는 거울 모드에서 좌우를 뒤집음)
+    pub fn suggest_move(&self) -> Option<SuggestedMove> {
+        let current_mino = self.current_mino?;
+
+        suggest_placement(&self.tetris_board, &current_mino, self.current_position)
+    }
+
+    // 하드드랍될 위치 획득
+    pub fn get_hard_drop_position(&self) -> Option<Point> {
+        match self.current_mino {
+            Some(current_mino) => {
+                let current_position = self.current_position;
+                let mut next_position = current_position.add_y(1);
+                loop {
+                    if !valid_mino(&self.tetris_board, &current_mino.cells, next_position) {
+                        next_position = next_position.add_y(-1);
+                        break;
+                    } else {
+                        next_position = next_position.add_y(1);
+                    }
+                }
+
+                Some(next_position)
+            }
+            None => None,
+        }
+    }
+
+    // 고스트(하드드랍 위치 미리보기)가 그려질 위치. 고스트를 끈 경우나 현재 미노가 없으면 None
+    pub fn ghost_position(&self) -> Option<Point> {
+        if let GhostStyle::Hidden = self.ghost_style {
+            return None;
+        }
+
+        self.get_hard_drop_position()
+    }
+
+    // 고스트가 그려질 칸과 현재 미노의 블럭. 현재 미노와 겹치는 칸은 제외
+    pub fn get_ghost_cells(&self) -> Vec<(Point, TetrisCell)> {
+        let current_mino = match self.current_mino {
+            Some(current_mino) => current_mino,
+            None => return vec![],
+        };
+
+        let ghost_position = match self.ghost_position() {
+            Some(ghost_position) => ghost_position,
+            None => return vec![],
+        };
+
+        let mino_points = |position: Point| {
+            current_mino
+                .cells
+                .iter()
+                .enumerate()
+                .flat_map(move |(mino_y, row)| {
+                    row.iter()
+                        .enumerate()
+                        .filter(|(_, cell)| !cell.is_empty())
+                        .map(move |(mino_x, cell)| {
+                            (position.move_xy(mino_x as i64, mino_y as i64), *cell)
+                        })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let current_points = mino_points(self.current_position);
+
+        mino_points(ghost_position)
+            .into_iter()
+            .filter(|(point, _)| !current_points.iter().any(|(current, _)| current == point))
+            .collect()
+    }
+
+    // 다음 미노가 보이는 영역으로 처음 들어올 위치의 칸 목록. 각 칸이 쌓인 블럭과 겹치는지 여부를 함께 반환
+    // 미리보기가 꺼져있거나 블럭이 아직 높게 쌓이지 않았다면 빈 목록
+    pub fn get_spawn_peek_cells(&self) -> Vec<(Point, bool)> {
+        if !self.spawn_peek {
+            return vec![];
+        }
+
+        let next_mino = match self.bag.front() {
+            Some(next_mino) => next_mino,
+            None => return vec![],
+        };
+
+        let hidden_row_count = self.tetris_board.hidden_row_count;
+
+        let highest_row = self
+            .tetris_board
+            .cells
+            .iter()
+            .position(|row| row.iter().any(|cell| !cell.is_empty()));
+
+        let stack_is_high = match highest_row {
+            Some(highest_row) => (highest_row as u32) < hidden_row_count + SPAWN_PEEK_ROW_COUNT,
+            None => false,
+        };
+
+        if !stack_is_high {
+            return vec![];
+        }
+
+        // 미노의 가장 아래 행이 보이는 영역 첫 행에 걸치는 위치
+        let bottom_row = next_mino
+            .cells
+            .iter()
+            .rposition(|row| row.iter().any(|cell| !cell.is_empty()))
+            .unwrap_or(0) as i64;
+
+        let spawn_point = Point::start_point(
+            self.tetris_board.column_count,
+            self.spawn_row,
+            self.spawn_column_offset,
+        );
+        let peek_point = Point {
+            x: spawn_point.x,
+            y: spawn_point.y.max(hidden_row_count as i64 - bottom_row),
+        };
+
+        next_mino
+            .occupied_points(peek_point)
+            .into_iter()
+            .map(|point| {
+                let conflict = self
+                    .tetris_board
+                    .cells
+                    .get(point.y as usize)
+                    .and_then(|row| row.get(point.x as usize))
+                    .map(|cell| !cell.is_empty())
+                    .unwrap_or(false);
+
+                (point, conflict)
+            })
+            .collect()
+    }
+
+    // 하드드랍 동작. 바닥까지 한번에 내리고 바로 고정. 내려간 칸 수를 반환 (현재 미노가 없으면 0)
+    pub fn hard_drop(&mut self) -> u32 {
+        let position = match self.get_hard_drop_position() {
+            Some(position) => position,
+            None => return 0,
+        };
+
+        let drop_distance = (position.y - self.current_position.y).max(0) as u32;
+
+        // 실제로 내려갔다면 이동으로 취급
+        if drop_distance > 0 {
+            self.current_position = position;
+            self.after_down();
+        }
+
+        self.lock_current_mino();
+
+        // 즉시 재출현 등으로 이미 새 미노가 나왔다면 다시 꺼내지 않음
+        if self.current_mino.is_none() {
+            self.tick();
+        }
+
+        drop_distance
+    }
+
+    // 미노 홀드
+    // 미노당 한번만 가능하며, 사용권은 미노가 고정될 때 다시 생김
+    pub fn hold(&mut self) {
+        if self.hold_used {
+            return;
+        }
+
+        let current_mino = match self.current_mino {
+            Some(current_mino) => current_mino,
+            // 게임 시작 직후 첫 미노가 나오기 전이라면 나올 차례인 미노를 가방에서 꺼내 바로 홀드
+            // 줄 삭제 후 다음 미노를 기다리는 중처럼 이미 미노가 나온 적이 있다면 무시
+            None => {
+                let first_spawned = self.record.spawn_count.iter().sum::<u32>() > 0;
+                if !self.on_play || self.is_clearing() || first_spawned {
+                    return;
+                }
+
+                let mino = self.get_mino();
+                self.record.count_spawn(mino.mino);
+                mino
+            }
+        };
+
+        // 홀드 칸에는 회전하지 않은 처음 모양으로 보관
+        let held = self
+            .hold
+            .replace(MinoShape::from(i32::from(current_mino.mino)));
+
+        self.hold_used = true;
+        self.freezed = false;
+        self.lock_delay_count = 0;
+        self.in_spin = SpinType::None;
+        self.last_action_was_rotation = false;
+        self.current_mino = None;
+
+        match held {
+            // 보관해둔 미노를 출현 위치에서 다시 시작
+            Some(held) => self.place_at_spawn(held),
+            // 처음 홀드라면 가방에서 다음 미노를 꺼냄
+            None => self.spawn_mino(),
+        }
+    }
+
+    // 180도 회전. 제자리에서 돌릴 수 없다면 180도 전용 킥 위치를 차례로 시도
+    pub fn double_rotate(&mut self) {
+        if let Some(current_mino) = &mut self.current_mino {
+            if current_mino.mino == Mino::O {
+                return;
+            }
+
+            let real_length = current_mino.rotation_length();
+
+            let mut next_shape = current_mino.cells;
+            rotate_right(&mut next_shape, real_length);
+            rotate_right(&mut next_shape, real_length);
+
+            let next_position = std::iter::once([0, 0])
+                .chain(KICK_INDEX_180[current_mino.rotation_count])
+                .map(|[x, y]| self.current_position.move_xy(x, -y))
+                .find(|&position| valid_mino(&self.tetris_board, &next_shape, position));
+
+            if let Some(next_position) = next_position {
+                current_mino.rotation_count = (current_mino.rotation_count + 2) % 4;
+                current_mino.cells = next_shape;
+                self.current_position = next_position;
+                // 180도 킥은 마지막 킥으로 T스핀 미니를 T스핀으로 올려주지 않음
+                if current_mino.mino == Mino::T {
+                    self.in_spin = valid_tspin(&self.tetris_board, current_mino, next_position, 0);
+                }
+
+                self.last_action_was_rotation = true;
+                // 바닥에 닿아있다면 고정 대기 상태 유지, 떨어질 수 있다면 해제
+                self.refresh_grounded();
+            }
+        }
+    }
+
+    // 게임오버. 원인은 기록에 남김
+    fn game_over(&mut self, reason: TopOut) {
+        self.pause_timer(Instant::now());
+        self.on_play = false;
+        self.lose = true;
+        self.dirty = true;
+        self.record.top_out = Some(reason);
+        self.current_mino = None;
+        self.freezed = false;
+        self.message = Some("Game Over".into());
+    }
+
+    // 목표 달성. 걸린 시간을 기록하고 게임 종료
+    fn game_clear(&mut self) {
+        self.pause_timer(Instant::now());
+        self.on_play = false;
+        self.win = true;
+        self.dirty = true;
+        self.current_mino = None;
+        self.freezed = false;
+        self.record.finish_time = Some(self.running_time);
+        self.message = Some("Clear!".into());
+    }
+
+    // 연습용으로 미리 정해둔 보드 모양을 불러옴 (TetrisBoard::to_ascii 형식)
+    // 글자로 적은 행들은 보드 맨 아래에 맞춰 놓고 그 위는 비움. 열 개수가 다르거나 행이 보드보다 많으면 에러
+    pub fn load_board_ascii(&mut self, text: &str) -> Result<(), TetrisError> {
+        let layout = TetrisBoard::from_ascii(text)?;
+        let column_count = self.tetris_board.column_count;
+        let row_count = (self.tetris_board.row_count as usize).min(self.tetris_board.cells.len());
+
+        if layout.column_count != column_count || layout.cells.len() > row_count {
+            return Err(TetrisError::InvalidDimensions);
+        }
+
+        let top = row_count - layout.cells.len();
+        for (y, row) in self
+            .tetris_board
+            .cells
+            .iter_mut()
+            .take(row_count)
+            .enumerate()
+        {
+            *row = match y.checked_sub(top) {
+                Some(layout_y) => layout.cells[layout_y].clone(),
+                None => vec![TetrisCell::Empty; column_count as usize],
+            };
+        }
+
+        self.cell_times = CellTimes::new(column_count, self.tetris_board.cells.len() as u32);
+        self.clearing_rows.clear();
+        self.clear_elapsed = 0;
+        self.update_tick_interval();
+        self.dirty = true;
+
+        Ok(())
+    }
+
+    // 보드 초기화
+    pub fn init_board(&mut self) -> Option<()> {
+        let column_count = self.tetris_board.column_count;
+        let row_count = self.tetris_board.row_count;
+
+        self.tetris_board = TetrisBoard {
+            cells: vec![vec![TetrisCell::Empty; column_count as usize]; row_count as usize],
+            row_count,
+            column_count,
+            board_height: self.tetris_board.board_height,
+            board_width: self.tetris_board.board_width,
+            hidden_row_count: self.tetris_board.hidden_row_count,
+        };
+        self.cell_times = CellTimes::new(column_count, row_count);
+        self.clearing_rows.clear();
+        self.clear_elapsed = 0;
+        self.fill_cheese();
+
+        Some(())
+    }
+
+    // 컨텍스트 초기화
+    pub fn init_context(&mut self) -> Option<()> {
+        self.back2back = None;
+        self.combo = None;
+        self.in_spin = SpinType::None;
+        self.last_action_was_rotation = false;
+        self.lock_delay_count = 0;
+        self.last_chance_used = false;
+        self.show_help = false;
+        self.message = None;
+        self.pending_garbage.clear();
+
+        Some(())
+    }
+
+    // 가방 초기화
+    pub fn init_bag(&mut self) -> Option<()> {
+        // 판마다 시드부터 다시 시작해야 기록된 시드로 같은 미노 순서를 재현할 수 있음
+        self.rng_seed = self.fixed_seed.unwrap_or_else(random_seed);
+        self.rng = Box::new(XorShiftRng::new(self.rng_seed));
+        self.bag = VecDeque::new();
+        self.manage_bag(); // 첫 미노가 나오기 전에도 넥스트가 보이도록 미리 채움
+        self.current_mino = None;
+        self.freezed = false;
+        self.auto_shift = None;
+        self.soft_dropping = false;
+        self.hold_used = false;
+        self.hold = None;
+
+        Some(())
+    }
+
+    // 점수 초기화
+    pub fn init_score(&mut self) -> Option<()> {
+        self.record = Default::default();
+        self.level = 1;
+        self.lines_cleared_total = 0;
+        self.set_tick_interval(level_tick_interval(1));
+
+        Some(())
+    }
+
+    pub fn init_running_time(&mut self) -> Option<()> {
+        self.paused = false;
+        self.running_time = Duration::ZERO;
+        self.timer_resumed_at = None;
+        self.logic_time = 0;
+        self.gravity_elapsed = 0;
+        self.former_lock_delay_count = 0;
+        self.input_log = vec![];
+        self.pending_events = vec![];
+        // 같은 시드면 매 판 같은 쓰레기 패턴이 나오도록 처음 상태로 되돌림
+        if let Some(garbage_challenge) = self.garbage_challenge {
+            self.garbage_rng = XorShiftRng::new(garbage_challenge.seed);
+            self.garbage_interval = garbage_challenge.garbage_interval;
+        }
+        self.garbage_elapsed = 0;
+        Some(())
+    }
+
+    // 게임 시작 상태로 전환하고 이번 게임 루프의 세대를 반환. 이미 진행중이라면 None
+    // 끝난 게임의 루프가 아직 대기중일 수 있으므로 세대를 올려서 이번 게임의 루프만 남김
+    pub fn begin_play(&mut self, now: Instant) -> Option<u32> {
+        if self.on_play {
+            return None;
+        }
+
+        // 게임 오버나 목표 달성으로 끝난 게임이라면 보드와 점수를 초기화하고 새로 시작
+        if self.lose || self.win {
+            self.init_game()?;
+        }
+
+        self.loop_generation = self.loop_generation.wrapping_add(1);
+
+        self.on_play = true;
+        self.lose = false;
+        self.win = false;
+        self.dirty = true;
+        self.resume_timer(now);
+
+        Some(self.loop_generation)
+    }
+
+    // 게임 종료. 시간 측정을 멈추고, 돌고 있던 루프는 다음 확인에서 멈춤
+    pub fn end_play(&mut self, now: Instant) {
+        self.pause_timer(now);
+        self.on_play = false;
+    }
+
+    // 해당 세대의 게임 루프가 계속 돌아야 하는지 여부. 게임이 끝났거나 새 게임이 시작됐다면 false
+    pub fn is_loop_running(&self, generation: u32) -> bool {
+        self.on_play && self.loop_generation == generation
+    }
+
+    // 게임 일시정지. 누르고 있던 이동키 상태와 처리하지 못한 입력은 버림
+    pub fn pause(&mut self, now: Instant) {
+        if !self.on_play || self.paused {
+            return;
+        }
+
+        self.paused = true;
+        self.dirty = true;
+        self.pause_timer(now);
+        self.auto_shift = None;
+        self.soft_dropping = false;
+        self.pending_events.clear();
+    }
+
+    // 일시정지 해제
+    pub fn resume(&mut self, now: Instant) {
+        if !self.paused {
+            return;
+        }
+
+        self.paused = false;
+        self.show_help = false;
+        self.dirty = true;
+        self.resume_timer(now);
+    }
+
+    // 일시정지 키 입력. 일시정지 상태라면 재개
+    pub fn toggle_pause(&mut self, now: Instant) {
+        if self.paused {
+            self.resume(now);
+        } else {
+            self.pause(now);
+        }
+    }
+
+    // 조작법 도움말 표시/숨김. 보여주는 동안은 일시정지
+    pub fn toggle_help(&mut self, now: Instant) {
+        if self.show_help {
+            self.resume(now);
+        } else if self.on_play {
+            self.pause(now);
+            self.show_help = true;
+        }
+    }
+
+    // 창이 포커스를 잃었을 때 호출. 자동 일시정지 설정이면 일시정지
+    pub fn on_focus_lost(&mut self, now: Instant) {
+        if self.auto_pause {
+            self.pause(now);
+        }
+    }
+
+    // 시간 측정 시작/재개
+    pub fn resume_timer(&mut self, now: Instant) {
+        if self.timer_resumed_at.is_none() {
+            self.timer_resumed_at = Some(now);
+        }
+    }
+
+    // 시간 측정 중단. 지금까지 흐른 시간은 running_time에 누적
+    pub fn pause_timer(&mut self, now: Instant) {
+        self.update_running_time(now);
+        self.timer_resumed_at = None;
+    }
+
+    // 재개 시점부터 흐른 시간을 running_time에 누적하고 기준점을 갱신
+    pub fn update_running_time(&mut self, now: Instant) {
+        if let Some(resumed_at) = self.timer_resumed_at {
+            self.running_time += now.saturating_duration_since(resumed_at);
+            self.timer_resumed_at = Some(now);
+        }
+    }
+
+    // 화면 표시용 점수, 레벨, 지운 줄 수
+    pub fn stats(&self) -> GameStats {
+        GameStats {
+            score: self.record.score,
+            level: self.level,
+            lines: self.lines_cleared_total,
+        }
+    }
+
+    // 화면에 보여줄 상태를 복사한 읽기 전용 값. 잠금을 푼 뒤에도 같은 시점의 상태로 그릴 수 있음
+    pub fn snapshot(&self) -> GameView {
+        GameView {
+            tetris_board: self.visible_board(),
+            current_mino: self.current_mino,
+            current_position: self.current_position,
+            ghost_position: self.ghost_position(),
+            ghost_cells: self.get_ghost_cells(),
+            spawn_peek_cells: self.get_spawn_peek_cells(),
+            clearing_rows: self.clearing_rows.clone(),
+            clear_elapsed: self.clear_elapsed,
+
+            stats: self.stats(),
+            record: self.record.clone(),
+            combo: self.combo,
+            back2back: self.back2back,
+            next: self.next_preview(),
+            hold: self.hold,
+            message: self.message.clone(),
+            play_time: self.play_time(Instant::now()),
+
+            lose: self.lose,
+            win: self.win,
+            paused: self.paused,
+            show_help: self.show_help,
+            help_lines: self.key_bindings.help_lines(),
+
+            block_style: self.block_style,
+            theme: self.theme.clone(),
+            ghost_style: self.ghost_style,
+            show_grid: self.show_grid,
+            mirror: self.mirror,
+            next_preview_count: self.next_preview_count,
+        }
+    }
+
+    // 현재까지의 플레이 시간 (일시정지 구간 제외)
+    pub fn play_time(&self, now: Instant) -> Duration {
+        match self.timer_resumed_at {
+            Some(resumed_at) => self.running_time + now.saturating_duration_since(resumed_at),
+            None => self.running_time,
+        }
+    }
+
+    // 게임 초기화
+    pub fn init_game(&mut self) -> Option<()> {
+        self.dirty = true;
+        self.init_bag()?;
+        self.init_board()?;
+        self.init_score()?;
+        self.init_context()?;
+        self.init_running_time()?;
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{J_DEFAULT_COLOR, L_DEFAULT_COLOR};
+    use crate::options::theme::PanelBorder;
+    use crate::util::{daily_seed, lock_or_recover};
+
+    // 줄 삭제 연출 없이 바로 지우는 게임. 보드는 to_ascii 형식으로 바닥부터 채우고 mino를 출현 위치에 둠
+    fn game_with(board: &str, mino: MinoShape) -> GameInfo {
+        let mut game_info = GameInfo::with_option(GameOption {
+            line_clear_delay: 0,
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.on_play = true;
+        if !board.is_empty() {
+            game_info.load_board_ascii(board).unwrap();
+        }
+        game_info.place_at_spawn(mino);
+
+        game_info
+    }
+
+    #[test]
+    fn moves_until_the_wall() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        assert!(game_info.move_left());
+        assert_eq!(game_info.current_position.x, 2);
+
+        while game_info.move_left() {}
+        assert_eq!(game_info.current_position.x, 0);
+        assert!(!game_info.move_left());
+
+        while game_info.move_right() {}
+        assert_eq!(game_info.current_position.x, 7);
+    }
+
+    #[test]
+    fn move_down_stops_on_the_floor() {
+        let mut game_info = game_with("", MinoShape::O);
+
+        while game_info.move_down() {}
+
+        assert!(game_info.is_grounded());
+        assert_eq!(
+            game_info.get_hard_drop_position(),
+            Some(game_info.current_position)
+        );
+    }
+
+    #[test]
+    fn rotates_both_ways() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        assert!(game_info.rotate(RotateDirection::Clockwise));
+        assert_eq!(game_info.rotation_state(), Some(1));
+
+        assert!(game_info.rotate(RotateDirection::CounterClockwise));
+        assert_eq!(game_info.rotation_state(), Some(0));
+
+        assert!(game_info.rotate(RotateDirection::CounterClockwise));
+        assert_eq!(game_info.rotation_state(), Some(3));
+    }
+
+    #[test]
+    fn rotation_kicks_off_the_wall() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        game_info.right_rotate();
+        while game_info.move_left() {}
+        // R 상태의 T는 4x4 영역의 왼쪽 열이 비어있으므로 영역이 벽 밖까지 나감
+        assert_eq!(game_info.current_position.x, -1);
+
+        game_info.left_rotate();
+
+        let current_mino = game_info.current_mino.unwrap();
+        assert_eq!(current_mino.rotation_count, 0);
+        assert!(valid_mino(
+            &game_info.tetris_board,
+            &current_mino.cells,
+            game_info.current_position
+        ));
+    }
+
+    #[test]
+    fn o_mino_does_not_rotate() {
+        let mut game_info = game_with("", MinoShape::O);
+
+        assert!(!game_info.rotate(RotateDirection::Clockwise));
+        assert_eq!(game_info.rotation_state(), Some(0));
+    }
+
+    #[test]
+    fn hard_drop_clears_a_line() {
+        let mut game_info = game_with("GGG....GGG\nGGGGGGGG.G", MinoShape::I);
+
+        game_info.hard_drop();
+
+        assert_eq!(game_info.record.line, 1);
+        assert_eq!(game_info.lines_cleared_total, 1);
+        let row_count = game_info.tetris_board.row_count as usize;
+        assert_eq!(
+            game_info.tetris_board.cells[row_count - 2],
+            vec![TetrisCell::Empty; 10]
+        );
+        assert_eq!(
+            game_info.tetris_board.cells[row_count - 1]
+                .iter()
+                .map(TetrisCell::to_ascii)
+                .collect::<String>(),
+            "GGGGGGGG.G"
+        );
+    }
+
+    #[test]
+    fn single_scores_by_level() {
+        let mut game_info = game_with("GGG....GGG\nGGGGGGGG.G", MinoShape::I);
+        // 레벨은 지운 줄 수로 다시 계산되므로 줄 수도 맞춰둠
+        game_info.lines_cleared_total = LINES_PER_LEVEL;
+        game_info.level = 2;
+
+        game_info.hard_drop();
+
+        assert_eq!(game_info.record.score, 200);
+        assert_eq!(game_info.combo, Some(0));
+    }
+
+    #[test]
+    fn tetris_scores_and_counts_as_quad() {
+        let mut game_info = game_with(
+            "GGGGGGGGG.\nGGGGGGGGG.\nGGGGGGGGG.\nGGGGGGGGG.\nGGGGGGGG.G",
+            MinoShape::I,
+        );
+
+        game_info.right_rotate();
+        while game_info.move_right() {}
+        game_info.hard_drop();
+
+        assert_eq!(game_info.record.line, 4);
+        assert_eq!(game_info.record.quad, 1);
+        assert_eq!(game_info.record.score, 800);
+        assert_eq!(game_info.back2back, Some(0));
+        assert_eq!(game_info.message.as_deref(), Some("Quad"));
+    }
+
+    #[test]
+    fn combo_adds_a_bonus() {
+        let mut game_info = game_with("GGG....GGG\nGGG....GGG\nGGGGGGGG.G", MinoShape::I);
+
+        game_info.hard_drop();
+        game_info.place_at_spawn(MinoShape::I);
+        game_info.hard_drop();
+
+        // 100 + (100 + 콤보 1 x 50)
+        assert_eq!(game_info.combo, Some(1));
+        assert_eq!(game_info.record.score, 250);
+    }
+
+    #[test]
+    fn single_mino_list_spawns_only_that_mino() {
+        for bag_mode in [
+            BagType::NoBag,
+            BagType::SevenBag,
+            BagType::FourteenBag,
+            BagType::Classic,
+        ] {
+            let mut game_info = GameInfo::with_option(GameOption {
+                mino_list: vec![MinoShape::S],
+                bag_mode,
+                seed: Some(1),
+                ..Default::default()
+            });
+            game_info.on_play = true;
+
+            for _ in 0..100 {
+                game_info.spawn_mino();
+                assert_eq!(game_info.current_mino.unwrap().mino, Mino::S);
+                game_info.current_mino = None;
+            }
+            assert_eq!(game_info.record.spawn_count_of(Mino::S), 100);
+        }
+    }
+
+    #[test]
+    fn set_mino_list_refills_the_bag() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        game_info.set_mino_list(vec![MinoShape::O]);
+
+        assert!(game_info.bag.iter().all(|mino| mino.mino == Mino::O));
+        // 떨어지는 중인 미노는 그대로
+        assert_eq!(game_info.current_mino.unwrap().mino, Mino::T);
+    }
+
+    #[test]
+    fn pausing_does_not_inflate_play_time() {
+        let mut game_info = game_with("", MinoShape::T);
+        let start = Instant::now();
+        let millis = |ms: u64| start + Duration::from_millis(ms);
+
+        game_info.resume_timer(start);
+        game_info.pause(millis(1_500));
+        // 일시정지한 채로 10초가 지나도 시간은 그대로
+        assert_eq!(
+            game_info.play_time(millis(11_500)),
+            Duration::from_millis(1_500)
+        );
+
+        game_info.resume(millis(11_500));
+        assert_eq!(
+            game_info.play_time(millis(12_250)),
+            Duration::from_millis(2_250)
+        );
+    }
+
+    // 숨겨진 행(0~3)만 비워두고 나머지를 쓰레기 블럭으로 채운 보드에서, 출현 위치에 T를 둔 게임
+    // pocket_row가 있다면 그 행부터 두 줄을 비워서 쌓인 블럭 아래에 미노가 들어갈 빈 공간을 만듦
+    fn lock_out_game(lock_out_leniency: bool, pocket_row: Option<usize>) -> GameInfo {
+        let mut game_info = GameInfo::with_option(GameOption {
+            lock_out_leniency,
+            line_clear_delay: 0,
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.on_play = true;
+        for row in game_info.tetris_board.cells.iter_mut().skip(4) {
+            *row = vec![TetrisCell::Garbage; 10];
+            row[0] = TetrisCell::Empty;
+        }
+        if let Some(pocket_row) = pocket_row {
+            for row in game_info
+                .tetris_board
+                .cells
+                .iter_mut()
+                .skip(pocket_row)
+                .take(2)
+            {
+                *row = vec![TetrisCell::Empty; 10];
+            }
+        }
+        game_info.place_at_spawn(MinoShape::T);
+
+        game_info
+    }
+
+    #[test]
+    fn lock_out_ends_the_game_by_default() {
+        let mut game_info = lock_out_game(false, Some(10));
+
+        game_info.hard_drop();
+
+        assert!(game_info.lose);
+        assert_eq!(game_info.record.top_out, Some(TopOut::LockOut));
+    }
+
+    #[test]
+    fn lenient_lock_out_settles_one_row_lower() {
+        let mut game_info = lock_out_game(true, Some(10));
+        let spawn_y = game_info.current_position.y;
+
+        game_info.hard_drop();
+
+        // 쌓인 블럭 위에 락아웃으로 고정되는 대신 아래의 빈 공간으로 내려감
+        assert!(!game_info.lose);
+        assert!(game_info.last_chance_used);
+        assert!(game_info.current_position.y > spawn_y);
+        assert!(!game_info.is_lock_out());
+
+        // 내려간 자리에서는 락아웃 없이 고정됨
+        game_info.hard_drop();
+        assert!(!game_info.lose);
+        assert!(!game_info.tetris_board.cells[11][4].is_empty());
+    }
+
+    #[test]
+    fn lenient_lock_out_through_gravity() {
+        let mut game_info = lock_out_game(true, Some(10));
+
+        // 바닥에 닿은 틱에는 대기 상태로만 전환되고, 다음 틱에 고정하려다 아래로 옮겨짐
+        game_info.tick();
+        game_info.tick();
+
+        assert!(!game_info.lose);
+        assert!(game_info.last_chance_used);
+        assert!(!game_info.is_lock_out());
+    }
+
+    #[test]
+    fn lenient_lock_out_without_room_below_ends_the_game() {
+        let mut game_info = lock_out_game(true, None);
+
+        game_info.hard_drop();
+
+        assert!(game_info.lose);
+        assert_eq!(game_info.record.top_out, Some(TopOut::LockOut));
+    }
+
+    #[test]
+    fn partly_visible_lock_is_not_a_lock_out() {
+        let mut game_info = lock_out_game(false, None);
+        // 보이는 영역의 첫 행을 비워서 미노의 아래 행이 그 행에 걸치도록 함
+        game_info.tetris_board.cells[4] = vec![TetrisCell::Empty; 10];
+        // 다음 미노가 출현 위치에서 겹치지 않도록 왼쪽 벽에 붙여서 고정
+        while game_info.move_left() {}
+
+        game_info.hard_drop();
+
+        assert!(!game_info.lose);
+        assert_eq!(game_info.record.top_out, None);
+    }
+
+    #[test]
+    fn spawning_into_a_filled_hidden_cell_is_a_block_out() {
+        let mut game_info = game_with("", MinoShape::O);
+        game_info.current_mino = None;
+        // 출현 위치의 숨겨진 행에 블럭 하나
+        game_info.tetris_board.cells[3][4] = TetrisCell::Garbage;
+
+        game_info.spawn_mino();
+
+        assert!(game_info.lose);
+        assert_eq!(game_info.record.top_out, Some(TopOut::BlockOut));
+    }
+
+    #[test]
+    fn freezed_is_set_on_landing_and_cleared_by_the_lock() {
+        let mut game_info = game_with("", MinoShape::O);
+        assert!(!game_info.freezed);
+
+        while game_info.move_down() {}
+        assert!(game_info.freezed);
+
+        // 고정 대기 중 다음 중력 틱에 고정
+        game_info.tick();
+        assert!(game_info.current_mino.is_none());
+        assert!(!game_info.freezed);
+    }
+
+    #[test]
+    fn freezed_is_cleared_when_moving_off_a_ledge() {
+        let mut game_info = game_with("GGGGG.....", MinoShape::O);
+        while game_info.move_left() {}
+
+        while game_info.move_down() {}
+        assert!(game_info.freezed);
+
+        // 오른쪽 빈 곳으로 옮기면 다시 떨어질 수 있음
+        for _ in 0..5 {
+            game_info.move_right();
+        }
+        assert!(!game_info.freezed);
+    }
+
+    #[test]
+    fn held_das_carries_over_to_the_next_piece() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        game_info.apply_event(Event::LeftMove);
+        game_info.advance(game_info.das as u64 + 10);
+        assert!(!game_info.move_left());
+
+        game_info.hard_drop();
+
+        // 새 미노는 DAS를 다시 기다리지 않고 출현하자마자 벽까지 밀림
+        let spawn_x = Point::start_point(10, game_info.spawn_row, 0).x;
+        assert!(game_info.current_mino.is_some());
+        assert!(game_info.current_position.x < spawn_x);
+        assert!(!game_info.move_left());
+    }
+
+    #[test]
+    fn released_das_does_not_carry_over() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        game_info.apply_event(Event::LeftMove);
+        game_info.advance(game_info.das as u64 + 10);
+        game_info.apply_event(Event::LeftRelease);
+        game_info.hard_drop();
+
+        let spawn_x = Point::start_point(10, game_info.spawn_row, 0).x;
+        assert_eq!(game_info.current_position.x, spawn_x);
+    }
+
+    #[test]
+    fn preview_count_is_separate_from_buffer_count() {
+        let mut game_info = GameInfo::with_option(GameOption {
+            next_buffer_count: 14,
+            next_preview_count: 5,
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.init_bag();
+
+        for _ in 0..30 {
+            assert_eq!(game_info.next_preview().len(), 5);
+            assert!(game_info.peek_next(14).is_some());
+            game_info.get_mino();
+        }
+    }
+
+    // 정해둔 값을 차례로 돌려주는 난수 생성기
+    #[derive(Debug)]
+    struct ScriptedRng {
+        values: Vec<u32>,
+        index: usize,
+    }
+
+    impl Rng for ScriptedRng {
+        fn next_u32(&mut self) -> u32 {
+            let value = self.values[self.index % self.values.len()];
+            self.index += 1;
+            value
+        }
+    }
+
+    fn bag_with(values: Vec<u32>) -> Vec<Mino> {
+        let mut game_info = GameInfo::with_option(GameOption::default());
+        game_info.set_rng(Box::new(ScriptedRng { values, index: 0 }));
+        game_info.bag.clear();
+        game_info.fill_bag().unwrap();
+
+        game_info
+            .bag
+            .iter()
+            .map(|mino_shape| mino_shape.mino)
+            .collect()
+    }
+
+    #[test]
+    fn seven_bag_follows_the_injected_rng() {
+        // 피셔-예이츠에서 매번 자기 자리를 고르면 목록 순서 그대로
+        assert_eq!(
+            bag_with(vec![6, 5, 4, 3, 2, 1]),
+            vec![
+                Mino::I,
+                Mino::L,
+                Mino::J,
+                Mino::S,
+                Mino::Z,
+                Mino::O,
+                Mino::T
+            ]
+        );
+        // 매번 맨 앞을 고르면 한칸씩 앞으로 밀림
+        assert_eq!(
+            bag_with(vec![0]),
+            vec![
+                Mino::L,
+                Mino::J,
+                Mino::S,
+                Mino::Z,
+                Mino::O,
+                Mino::T,
+                Mino::I
+            ]
+        );
+    }
+
+    #[test]
+    fn seventy_spawns_in_bag_mode_count_ten_each() {
+        let mut game_info = GameInfo::with_option(GameOption {
+            seed: Some(3),
+            ..Default::default()
+        });
+        game_info.on_play = true;
+
+        for spawned in 1..=70 {
+            game_info.spawn_mino();
+            game_info.current_mino = None;
+
+            // 가방 중간에도 미노별 개수 차이는 1 이하
+            let counts = game_info.record.spawn_count;
+            let min = counts.iter().min().unwrap();
+            let max = counts.iter().max().unwrap();
+            assert!(max - min <= 1, "{} spawns: {:?}", spawned, counts);
+        }
+
+        assert_eq!(game_info.record.spawn_count, [10; 7]);
+    }
+
+    fn garbage_pattern(seed: u64) -> (String, Vec<u64>) {
+        let mut game_info = GameInfo::with_option(GameOption {
+            garbage_challenge: Some(GarbageChallenge {
+                seed,
+                max_lines: 3,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        game_info.on_play = true;
+
+        let mut intervals = vec![];
+        for _ in 0..6 {
+            game_info.add_random_garbage();
+            intervals.push(game_info.garbage_interval);
+        }
+
+        (game_info.tetris_board.to_ascii(), intervals)
+    }
+
+    #[test]
+    fn garbage_challenge_is_reproducible_with_a_fixed_seed() {
+        let (board, intervals) = garbage_pattern(42);
+
+        assert_eq!(garbage_pattern(42), (board.clone(), intervals.clone()));
+        assert_ne!(garbage_pattern(43).0, board);
+        // 올라올 때마다 간격이 줄어듦
+        assert!(intervals.windows(2).all(|pair| pair[1] <= pair[0]));
+    }
+
+    #[test]
+    fn hold_and_hard_drop_on_the_same_frame_drop_the_swapped_in_mino() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        // 도착 순서와 관계없이 홀드가 먼저 처리됨
+        game_info.queue_event(Event::HardDrop);
+        game_info.queue_event(Event::Hold);
+        game_info.flush_events();
+
+        let board = game_info.tetris_board.to_ascii();
+        assert_eq!(game_info.hold.map(|mino| mino.mino), Some(Mino::T));
+        assert!(!board.contains('T'));
+        assert_eq!(
+            board
+                .chars()
+                .filter(|&cell| cell != '.' && cell != '\n')
+                .count(),
+            4
+        );
+    }
+
+    #[test]
+    fn same_daily_seed_gives_the_same_first_pieces() {
+        let first_pieces = |date: &str| {
+            let mut game_info = GameInfo::with_option(GameOption {
+                seed: Some(daily_seed(date)),
+                ..Default::default()
+            });
+            (0..14)
+                .map(|_| game_info.get_mino().mino)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(first_pieces("2024-03-01"), first_pieces("2024-03-01"));
+        assert_ne!(first_pieces("2024-03-01"), first_pieces("2024-03-02"));
+    }
+
+    // 바닥에 내려놓은 T를 300ms마다 좌우로 한칸씩 흔든 뒤, 고정됐는지 여부
+    fn locked_after_moves(policy: LockDelayPolicy, move_count: usize, idle: u64) -> bool {
+        let mut game_info = game_with("", MinoShape::T);
+        game_info.lock_delay_policy = policy;
+        while game_info.move_down() {}
+
+        for i in 0..move_count {
+            game_info.advance(300);
+            let (press, release) = if i % 2 == 0 {
+                (Event::LeftMove, Event::LeftRelease)
+            } else {
+                (Event::RightMove, Event::RightRelease)
+            };
+            game_info.queue_event(press);
+            game_info.queue_event(release);
+        }
+        game_info.advance(idle);
+
+        game_info.tetris_board.to_ascii().contains('T')
+    }
+
+    #[test]
+    fn step_reset_locks_despite_moves() {
+        assert!(locked_after_moves(LockDelayPolicy::StepReset, 8, 300));
+    }
+
+    #[test]
+    fn move_reset_locks_only_after_the_cap() {
+        let policy = LockDelayPolicy::MoveReset { max_reset: 8 };
+
+        assert!(!locked_after_moves(policy, 8, 300));
+        assert!(locked_after_moves(policy, 12, 300));
+    }
+
+    #[test]
+    fn infinite_never_locks_by_gravity() {
+        assert!(!locked_after_moves(LockDelayPolicy::Infinite, 20, 10_000));
+    }
+
+    #[test]
+    fn grounded_on_the_floor_and_on_the_stack() {
+        let mut on_floor = game_with("", MinoShape::O);
+        assert!(!on_floor.is_grounded());
+        while on_floor.move_down() {}
+        assert!(on_floor.is_grounded());
+
+        let mut on_stack = game_with("GGGGGGGGG.\nGGGGGGGGG.", MinoShape::O);
+        while on_stack.move_down() {}
+        assert!(on_stack.is_grounded());
+        assert_eq!(on_stack.current_position.y, on_floor.current_position.y - 2);
+    }
+
+    #[test]
+    fn not_grounded_without_a_mino() {
+        let mut game_info = game_with("", MinoShape::O);
+        game_info.current_mino = None;
+
+        assert!(!game_info.is_grounded());
+    }
+
+    // 오른쪽을 향한 T를 바닥 구멍 위에 두고 왼쪽으로 돌려서 T스핀 미니 자리에 넣음
+    fn rotated_into_tspin_mini() -> GameInfo {
+        let mut game_info = game_with("...G......\n..........\nGGGG.GGGGG", MinoShape::T);
+        let mut t = MinoShape::T;
+        let length = t.rotation_length();
+        rotate_right(&mut t.cells, length);
+        t.rotation_count = 1;
+        game_info.current_mino = Some(t);
+        game_info.current_position = Point {
+            x: 3,
+            y: game_info.tetris_board.row_count as i64 - 3,
+        };
+
+        game_info.left_rotate();
+        game_info
+    }
+
+    #[test]
+    fn rotate_then_lock_counts_as_a_spin() {
+        let mut game_info = rotated_into_tspin_mini();
+        assert!(matches!(game_info.in_spin, SpinType::Mini));
+
+        game_info.hard_drop();
+
+        assert_eq!(game_info.record.score, 100);
+    }
+
+    #[test]
+    fn rotate_then_move_then_lock_is_not_a_spin() {
+        let mut game_info = rotated_into_tspin_mini();
+
+        assert!(game_info.move_right());
+        assert!(game_info.move_left());
+        game_info.hard_drop();
+
+        assert_eq!(game_info.record.score, 0);
+    }
+
+    #[test]
+    fn restored_sequence_state_reproduces_the_next_twenty_pieces() {
+        let mut original = GameInfo::with_option(GameOption {
+            seed: Some(3),
+            ..Default::default()
+        });
+        // 가방 중간에서 내보내도 이어지는지 확인하기 위해 몇개 꺼내둠
+        for _ in 0..5 {
+            original.get_mino();
+        }
+        // 공유 문자열을 거쳐서 받은 쪽에서 복원
+        let shared = original.export_sequence_state().unwrap().to_share_string();
+        let state = SequenceState::from_share_string(&shared).unwrap();
+
+        let mut restored = GameInfo::with_option(GameOption {
+            seed: Some(99),
+            ..Default::default()
+        });
+        restored.restore_sequence_state(&state);
+
+        let next_twenty = |game_info: &mut GameInfo| {
+            (0..20)
+                .map(|_| game_info.get_mino().mino)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(next_twenty(&mut restored), next_twenty(&mut original));
+    }
+
+    #[test]
+    fn clearing_the_last_cheese_row_wins_with_the_time() {
+        let mut game_info = GameInfo::with_option(GameOption {
+            line_clear_delay: 0,
+            cheese_race: Some(CheeseRace {
+                garbage_rows: 1,
+                seed: 4,
+            }),
+            ..Default::default()
+        });
+        game_info.on_play = true;
+        game_info.running_time = Duration::from_secs(42);
+        let row_count = game_info.tetris_board.row_count as usize;
+        let hole_column = game_info.tetris_board.cells[row_count - 1]
+            .iter()
+            .position(TetrisCell::is_empty)
+            .unwrap();
+        assert_eq!(game_info.tetris_board.garbage_row_count(), 1);
+
+        // 세운 I를 구멍에 꽂음 (4x4 영역의 세번째 열)
+        let mut i = MinoShape::I;
+        let length = i.rotation_length();
+        rotate_right(&mut i.cells, length);
+        i.rotation_count = 1;
+        game_info.current_mino = Some(i);
+        game_info.current_position = Point {
+            x: hole_column as i64 - 2,
+            y: 0,
+        };
+        game_info.hard_drop();
+
+        assert_eq!(game_info.tetris_board.garbage_row_count(), 0);
+        assert!(game_info.win);
+        assert!(!game_info.on_play);
+        assert_eq!(game_info.record.finish_time, Some(Duration::from_secs(42)));
+    }
+
+    #[test]
+    fn help_overlay_reflects_a_rebound_key() {
+        let mut game_info = game_with("", MinoShape::T);
+        let hard_drop_line = |game_info: &GameInfo| {
+            game_info
+                .snapshot()
+                .help_lines
+                .into_iter()
+                .find(|line| line.starts_with("Hard Drop"))
+                .unwrap()
+        };
+        assert_eq!(hard_drop_line(&game_info), "Hard Drop    Space");
+
+        game_info.key_bindings.hard_drop = 13;
+
+        assert_eq!(hard_drop_line(&game_info), "Hard Drop    Enter");
+    }
+
+    #[test]
+    fn starting_twice_does_not_double_advance() {
+        let mut game_info = game_with("", MinoShape::O);
+        game_info.on_play = false;
+
+        // 시작 버튼을 두번 눌러도 루프는 하나만 생김
+        let loops: Vec<u32> = (0..2)
+            .filter_map(|_| game_info.begin_play(Instant::now()))
+            .collect();
+        assert_eq!(loops.len(), 1);
+
+        for _ in 0..5 {
+            for &generation in &loops {
+                if game_info.is_loop_running(generation) {
+                    game_info.advance(100);
+                }
+            }
+        }
+        assert_eq!(game_info.logic_time, 500);
+    }
+
+    #[test]
+    fn restarting_stops_the_previous_loop() {
+        let mut game_info = game_with("", MinoShape::O);
+        game_info.on_play = false;
+
+        let first = game_info.begin_play(Instant::now()).unwrap();
+        game_info.game_over(TopOut::BlockOut);
+        let second = game_info.begin_play(Instant::now()).unwrap();
+
+        assert!(!game_info.is_loop_running(first));
+        assert!(game_info.is_loop_running(second));
+    }
+
+    // 오른쪽 끝 열만 빈 줄을 lines개 깔고 세운 I를 꽂아서 지운 뒤 늘어난 점수 반환
+    // 맨 아래 줄은 남겨서 퍼펙트 클리어가 되지 않게 하고, 콤보 보너스가 섞이지 않도록 콤보는 끊어둠
+    fn clear_with_vertical_i(game_info: &mut GameInfo, lines: usize) -> u64 {
+        let board = vec!["GGGGGGGGG."; lines].join("\n") + "\nG.GGGGGGGG";
+        game_info.load_board_ascii(&board).unwrap();
+        game_info.combo = None;
+
+        let mut i = MinoShape::I;
+        let length = i.rotation_length();
+        rotate_right(&mut i.cells, length);
+        i.rotation_count = 1;
+        game_info.current_mino = Some(i);
+        game_info.current_position = Point { x: 7, y: 0 };
+
+        let (score, line) = (game_info.record.score, game_info.record.line);
+        game_info.hard_drop();
+        assert_eq!(game_info.record.line - line, lines as u32);
+
+        game_info.record.score - score
+    }
+
+    #[test]
+    fn tetris_after_tetris_gets_the_back_to_back_bonus() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        assert_eq!(clear_with_vertical_i(&mut game_info, 4), 800);
+        assert_eq!(clear_with_vertical_i(&mut game_info, 4), 1200);
+    }
+
+    #[test]
+    fn single_breaks_the_back_to_back_chain() {
+        let mut game_info = game_with("", MinoShape::T);
+
+        assert_eq!(clear_with_vertical_i(&mut game_info, 4), 800);
+        assert_eq!(clear_with_vertical_i(&mut game_info, 1), 100);
+        assert_eq!(clear_with_vertical_i(&mut game_info, 4), 800);
+    }
+
+    #[test]
+    fn rapid_start_and_stop_from_many_threads() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        let game_info = Arc::new(Mutex::new(game_with("", MinoShape::O)));
+        lock_or_recover(&game_info).on_play = false;
+        let started = Arc::new(AtomicU32::new(0));
+
+        // 시작/종료 버튼을 연타하는 스레드들과 중력 루프 스레드가 같은 잠금을 두고 다툼
+        let buttons = (0..4).map(|_| {
+            let game_info = Arc::clone(&game_info);
+            let started = Arc::clone(&started);
+            std::thread::spawn(move || {
+                for _ in 0..200 {
+                    if lock_or_recover(&game_info)
+                        .begin_play(Instant::now())
+                        .is_some()
+                    {
+                        started.fetch_add(1, Ordering::SeqCst);
+                    }
+                    lock_or_recover(&game_info).end_play(Instant::now());
+                }
+            })
+        });
+        let ticker = {
+            let game_info = Arc::clone(&game_info);
+            std::thread::spawn(move || {
+                for _ in 0..800 {
+                    let mut game_info = lock_or_recover(&game_info);
+                    let generation = game_info.loop_generation;
+                    if game_info.is_loop_running(generation) {
+                        game_info.advance(16);
+                    }
+                }
+            })
+        };
+
+        for handle in buttons.collect::<Vec<_>>() {
+            handle.join().unwrap();
+        }
+        ticker.join().unwrap();
+
+        let mut game_info = lock_or_recover(&game_info);
+        let started = started.load(Ordering::SeqCst);
+        assert!(started > 0);
+        assert_eq!(game_info.loop_generation, started);
+        assert!(!game_info.on_play);
+
+        // 마지막으로 시작한 게임의 루프만 돎
+        let generation = game_info.begin_play(Instant::now()).unwrap();
+        assert!(game_info.is_loop_running(generation));
+        assert!(!game_info.is_loop_running(generation - 1));
+    }
+
+    #[test]
+    fn preview_stays_continuous_across_bag_boundaries() {
+        for preview_count in [1, 5, 7, 12] {
+            let mut game_info = GameInfo::with_option(GameOption {
+                seed: Some(11),
+                ..Default::default()
+            });
+            game_info.set_next_preview_count(preview_count);
+
+            // 미노를 하나 꺼낼 때마다 넥스트가 한칸씩 당겨지고, 뒤에 새 미노가 하나만 붙어야 함
+            for _ in 0..30 {
+                let preview = game_info.next_preview();
+                assert_eq!(preview.len(), preview_count);
+
+                let mino = game_info.get_mino();
+                let next = game_info.next_preview();
+
+                assert_eq!(mino.mino, preview[0].mino);
+                assert_eq!(
+                    next[..preview_count - 1]
+                        .iter()
+                        .map(|mino| mino.mino)
+                        .collect::<Vec<_>>(),
+                    preview[1..]
+                        .iter()
+                        .map(|mino| mino.mino)
+                        .collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_preview_count_shows_nothing() {
+        let mut game_info = game_with("", MinoShape::T);
+        game_info.set_next_preview_count(0);
+
+        assert!(game_info.next_preview().is_empty());
+        assert!(game_info.snapshot().next.is_empty());
+    }
+
+    fn draws(bag_mode: BagType, seed: u64, count: usize) -> Vec<Mino> {
+        let mut game_info = GameInfo::with_option(GameOption {
+            bag_mode,
+            seed: Some(seed),
+            ..Default::default()
+        });
+
+        (0..count).map(|_| game_info.get_mino().mino).collect()
+    }
+
+    fn count_of(minos: &[Mino], mino: Mino) -> usize {
+        minos.iter().filter(|&&e| e == mino).count()
+    }
+
+    #[test]
+    fn seven_bag_deals_each_mino_once_per_bag() {
+        for bag in draws(BagType::SevenBag, 5, 700).chunks(7) {
+            for mino in MinoShape::all() {
+                assert_eq!(count_of(bag, mino.mino), 1);
+            }
+        }
+    }
+
+    #[test]
+    fn fourteen_bag_deals_each_mino_twice_per_bag() {
+        let minos = draws(BagType::FourteenBag, 5, 1400);
+
+        for bag in minos.chunks(14) {
+            for mino in MinoShape::all() {
+                assert_eq!(count_of(bag, mino.mino), 2);
+            }
+        }
+        // 7가방과 달리 같은 미노가 연달아 나올 수 있음
+        assert!(minos.windows(2).any(|pair| pair[0] == pair[1]));
+    }
+
+    #[test]
+    fn no_bag_and_classic_are_roughly_uniform() {
+        for bag_mode in [BagType::NoBag, BagType::Classic] {
+            let minos = draws(bag_mode, 5, 7000);
+
+            for mino in MinoShape::all() {
+                let count = count_of(&minos, mino.mino);
+                assert!((800..=1200).contains(&count), "{:?} {}", mino.mino, count);
+            }
+        }
+    }
+
+    #[test]
+    fn classic_rarely_repeats_the_previous_mino() {
+        let repeats = |bag_mode| {
+            draws(bag_mode, 5, 7000)
+                .windows(2)
+                .filter(|pair| pair[0] == pair[1])
+                .count()
+        };
+
+        // 무작위라면 1/7 정도가 바로 앞과 같지만, 기록 방식은 다시 뽑으므로 훨씬 적음
+        let no_bag = repeats(BagType::NoBag);
+        assert!(no_bag > 800);
+        assert!(repeats(BagType::Classic) * 3 < no_bag);
+    }
+
+    #[test]
+    fn every_mode_is_reproducible_with_a_seed() {
+        let modes = || {
+            [
+                BagType::NoBag,
+                BagType::SevenBag,
+                BagType::FourteenBag,
+                BagType::Classic,
+            ]
+        };
+
+        for (first, second) in modes().into_iter().zip(modes()) {
+            assert_eq!(draws(first, 9, 100), draws(second, 9, 100));
+        }
+    }
+
+    #[test]
+    fn garbage_that_pushes_the_stack_off_the_top_ends_the_game() {
+        let mut game_info = game_with("", MinoShape::O);
+        game_info.current_mino = None;
+        game_info.tetris_board.cells[1][0] = TetrisCell::Garbage;
+
+        game_info.add_garbage(1, 9);
+        assert!(!game_info.lose);
+
+        game_info.add_garbage(1, 9);
+        assert!(game_info.lose);
+        assert_eq!(game_info.record.top_out, Some(TopOut::PushOut));
+    }
+
+    #[test]
+    fn hold_before_the_first_spawn_holds_the_first_mino() {
+        let mut game_info = GameInfo::with_option(GameOption {
+            seed: Some(2),
+            ..Default::default()
+        });
+        game_info.init_bag();
+        game_info.on_play = true;
+        let upcoming: Vec<Mino> = game_info
+            .peek_next(4)
+            .unwrap()
+            .iter()
+            .map(|mino| mino.mino)
+            .collect();
+
+        game_info.hold();
+
+        assert_eq!(game_info.hold.map(|mino| mino.mino), Some(upcoming[0]));
+        assert_eq!(
+            game_info.current_mino.map(|mino| mino.mino),
+            Some(upcoming[1])
+        );
+        assert_eq!(
+            game_info.next_preview()[..2]
+                .iter()
+                .map(|mino| mino.mino)
+                .collect::<Vec<_>>(),
+            upcoming[2..]
+        );
+        assert_eq!(game_info.record.spawn_count.iter().sum::<u32>(), 2);
+    }
+
+    #[test]
+    fn hold_between_minos_does_nothing() {
+        // 첫 미노가 나온 뒤 고정되고 다음 미노를 기다리는 상태
+        let mut game_info = game_with("", MinoShape::T);
+        game_info.spawn_mino();
+        game_info.current_mino = None;
+        let bag_len = game_info.bag.len();
+
+        game_info.hold();
+
+        assert!(game_info.hold.is_none());
+        assert!(game_info.current_mino.is_none());
+        assert_eq!(game_info.bag.len(), bag_len);
+    }
+
+    #[test]
+    fn moving_the_mino_leaves_the_locked_cells_untouched() {
+        // 움직이는 미노는 보드에 쓰지 않고 그릴 때만 겹쳐 그리므로 이동해도 고정된 블럭만 남음
+        let mut game_info = game_with("GG......GG\nGGG....GGG", MinoShape::T);
+        let board = game_info.tetris_board.to_ascii();
+
+        assert!(game_info.move_left());
+        assert_eq!(game_info.tetris_board.to_ascii(), board);
+        assert!(game_info.move_down());
+        assert!(game_info.move_right());
+        game_info.right_rotate();
+
+        assert_eq!(game_info.tetris_board.to_ascii(), board);
+        assert!(!game_info.tetris_board.to_ascii().contains('T'));
+    }
+
+    #[test]
+    fn suggested_events_place_the_mino_without_holes() {
+        for mino in [
+            MinoShape::I,
+            MinoShape::J,
+            MinoShape::L,
+            MinoShape::O,
+            MinoShape::T,
+        ] {
+            let mut game_info = game_with("", mino);
+            let suggested = game_info.suggest_move().unwrap();
+
+            for event in suggested.events {
+                game_info.apply_event(event);
+            }
+
+            // 빈 칸 위에 블럭이 있는 열이 없어야 함
+            let board = &game_info.tetris_board;
+            for x in 0..board.column_count as usize {
+                let column: Vec<_> = board.cells.iter().map(|row| row[x]).collect();
+                if let Some(top) = column.iter().position(|cell| !cell.is_empty()) {
+                    assert!(
+                        column[top..].iter().all(|cell| !cell.is_empty()),
+                        "{:?}",
+                        mino.mino
+                    );
+                }
+            }
+        }
+    }
+
+    // 다음 미노 출현 미리보기를 켠 게임. 보이는 영역 첫 행부터 stack_columns 열을 쓰레기 블럭으로 채움
+    // 넥스트를 보기 위해 가방을 채워둠
+    fn spawn_peek_game(stack_columns: std::ops::Range<usize>) -> GameInfo {
+        let mut game_info = GameInfo::with_option(GameOption {
+            spawn_peek: true,
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.init_game().unwrap();
+        for row in game_info.tetris_board.cells.iter_mut().skip(4) {
+            for x in stack_columns.clone() {
+                row[x] = TetrisCell::Garbage;
+            }
+        }
+
+        game_info
+    }
+
+    #[test]
+    fn colliding_spawn_peek_is_marked_as_warning() {
+        let game_info = spawn_peek_game(0..9);
+
+        let peek_cells = game_info.get_spawn_peek_cells();
+        assert!(peek_cells.iter().any(|&(_, conflict)| conflict));
+
+        let view = game_info.snapshot();
+        let mut tetris_board = view.overlay_board();
+        view.overlay_spawn_peek(&mut tetris_board);
+
+        for (point, conflict) in peek_cells {
+            let cell = tetris_board.cells[point.y as usize][point.x as usize];
+            if conflict {
+                assert!(point.y >= 4);
+                assert_eq!(cell, TetrisCell::Warning);
+            } else {
+                assert_eq!(cell, TetrisCell::Ghost);
+            }
+        }
+    }
+
+    #[test]
+    fn spawn_peek_without_collision_has_no_warning() {
+        // 출현 열은 비워두고 왼쪽 끝 열만 높이 쌓음
+        let game_info = spawn_peek_game(0..1);
+
+        let peek_cells = game_info.get_spawn_peek_cells();
+        assert!(!peek_cells.is_empty());
+        assert!(peek_cells.iter().all(|&(_, conflict)| !conflict));
+    }
+
+    #[test]
+    fn spawn_peek_is_hidden_on_a_low_stack() {
+        let mut game_info = spawn_peek_game(0..0);
+        game_info.load_board_ascii("GGGGGGGGG.").unwrap();
+
+        assert!(game_info.get_spawn_peek_cells().is_empty());
+    }
+
+    #[test]
+    fn adaptive_speed_changes_the_interval_with_the_fill() {
+        let mut game_info = GameInfo::with_option(GameOption {
+            adaptive_speed: Some(AdaptiveSpeed::default()),
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.update_tick_interval();
+        let empty_interval = game_info.tick_interval;
+
+        // 보이는 영역의 맨 위 두 줄만 남기고 채움
+        let nearly_full = ["GGGGGGGGG."; 18].join("\n");
+        game_info.load_board_ascii(&nearly_full).unwrap();
+        game_info.update_tick_interval();
+
+        assert_ne!(game_info.tick_interval, empty_interval);
+        assert!(game_info.tick_interval > empty_interval);
+    }
+
+    #[test]
+    fn adaptive_speed_is_off_by_default() {
+        let mut game_info = GameInfo::with_option(GameOption {
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info
+            .load_board_ascii(&["GGGGGGGGG."; 18].join("\n"))
+            .unwrap();
+        game_info.update_tick_interval();
+
+        assert_eq!(game_info.tick_interval, game_info.base_tick_interval);
+    }
+
+    #[test]
+    fn overriding_the_board_border_keeps_next_and_hold() {
+        let game_info = GameInfo::with_option(GameOption {
+            theme: Theme {
+                board_border: PanelBorder::new("#ff0000", 3.0),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+
+        let theme = game_info.snapshot().theme;
+        let default_theme = Theme::default();
+
+        assert_eq!(theme.board_border, PanelBorder::new("#ff0000", 3.0));
+        assert_ne!(theme.board_border, default_theme.board_border);
+        assert_eq!(theme.next_border, default_theme.next_border);
+        assert_eq!(theme.hold_border, default_theme.hold_border);
+    }
+
+    // 줄 삭제 연출이 있는 게임에서 I 미노로 한 줄을 지우고 연출이 끝날 때까지 진행
+    fn game_after_clear_animation(instant_respawn: bool) -> GameInfo {
+        let mut game_info = GameInfo::with_option(GameOption {
+            instant_respawn,
+            line_clear_delay: 300,
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.on_play = true;
+        game_info.load_board_ascii("GGG....GGG").unwrap();
+        game_info.place_at_spawn(MinoShape::I);
+
+        game_info.hard_drop();
+        assert!(game_info.is_clearing());
+        assert!(game_info.current_mino.is_none());
+
+        game_info.advance(300);
+        assert!(!game_info.is_clearing());
+
+        game_info
+    }
+
+    #[test]
+    fn instant_respawn_spawns_right_after_the_clear() {
+        let game_info = game_after_clear_animation(true);
+
+        assert!(game_info.current_mino.is_some());
+    }
+
+    #[test]
+    fn respawn_waits_for_the_next_tick_by_default() {
+        let mut game_info = game_after_clear_animation(false);
+
+        assert!(game_info.current_mino.is_none());
+
+        game_info.tick();
+        assert!(game_info.current_mino.is_some());
+    }
+
+    #[test]
+    fn transparent_ghost_uses_the_piece_color_and_alpha() {
+        let mut game_info = game_with("", MinoShape::J);
+        game_info.ghost_style = GhostStyle::TransparentPieceColor { alpha: 0.35 };
+
+        let view = game_info.snapshot();
+        let (points, cell, alpha) = view.ghost_overlay().unwrap();
+
+        assert_eq!(cell, J_DEFAULT_COLOR);
+        assert_eq!(alpha, 0.35);
+        let ghost_points: Vec<_> = view.ghost_cells.iter().map(|&(point, _)| point).collect();
+        assert_eq!(points, ghost_points);
+
+        // 보드에는 단색 고스트를 쓰지 않고 나중에 따로 덧그림
+        let tetris_board = view.overlay_board();
+        assert!(tetris_board
+            .cells
+            .iter()
+            .flatten()
+            .all(|&cell| cell != TetrisCell::Ghost));
+    }
+
+    #[test]
+    fn transparent_ghost_is_mirrored_with_the_board() {
+        let mut game_info = game_with("", MinoShape::J);
+        game_info.ghost_style = GhostStyle::TransparentPieceColor { alpha: 1.5 };
+        game_info.set_mirror(true);
+
+        let view = game_info.snapshot();
+        let (points, cell, alpha) = view.ghost_overlay().unwrap();
+
+        // 거울 모드의 J는 L처럼 보임
+        assert_eq!(cell, L_DEFAULT_COLOR);
+        assert_eq!(alpha, 1.0);
+        for (point, &(ghost_point, _)) in points.iter().zip(&view.ghost_cells) {
+            assert_eq!(point.x, 9 - ghost_point.x);
+            assert_eq!(point.y, ghost_point.y);
+        }
+    }
+
+    #[test]
+    fn solid_ghost_has_no_overlay() {
+        let game_info = game_with("", MinoShape::J);
+
+        let view = game_info.snapshot();
+
+        assert_eq!(view.ghost_overlay(), None);
+        assert!(view
+            .overlay_board()
+            .cells
+            .iter()
+            .flatten()
+            .any(|&cell| cell == TetrisCell::Ghost));
+    }
+
+    // 출현 행을 바꾼 게임. 첫 미노는 T로 고정
+    fn game_with_spawn_row(spawn_row: u32, board: &str) -> GameInfo {
+        let mut game_info = GameInfo::with_option(GameOption {
+            spawn_row,
+            line_clear_delay: 0,
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.on_play = true;
+        if !board.is_empty() {
+            game_info.load_board_ascii(board).unwrap();
+        }
+        game_info.bag.push_front(MinoShape::T);
+
+        game_info
+    }
+
+    #[test]
+    fn custom_spawn_row_places_the_piece_lower() {
+        let mut game_info = game_with_spawn_row(6, "");
+
+        game_info.spawn_mino();
+
+        assert!(!game_info.lose);
+        assert_eq!(game_info.current_position, Point { x: 3, y: 6 });
+        let mut points = game_info
+            .current_mino
+            .unwrap()
+            .occupied_points(game_info.current_position);
+        points.sort_by_key(|point| (point.y, point.x));
+        assert_eq!(
+            points,
+            vec![
+                Point { x: 4, y: 6 },
+                Point { x: 3, y: 7 },
+                Point { x: 4, y: 7 },
+                Point { x: 5, y: 7 },
+            ]
+        );
+    }
+
+    #[test]
+    fn spawning_on_a_custom_row_respects_collision() {
+        // 바닥에서 17번째 줄 = 보드의 7번째 행. 출현한 T의 아래 행과 겹침
+        let mut board = [".........."; 17];
+        board[0] = "....G.....";
+        let mut game_info = game_with_spawn_row(6, &board.join("\n"));
+
+        game_info.spawn_mino();
+
+        assert!(game_info.lose);
+        assert_eq!(game_info.record.top_out, Some(TopOut::BlockOut));
+    }
+
+    #[test]
+    fn spawn_row_is_clamped_into_the_board() {
+        let game_info = game_with_spawn_row(100, "");
+
+        // 숨겨진 행 4줄을 포함한 24행 보드에서 4x4 영역이 들어가는 가장 아래 행
+        assert_eq!(game_info.spawn_row, 20);
+    }
+
+    #[test]
+    fn focus_loss_pauses_the_game() {
+        let now = Instant::now();
+        let mut game_info = GameInfo::with_option(GameOption {
+            auto_pause: true,
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.begin_play(now).unwrap();
+
+        game_info.on_focus_lost(now);
+
+        assert!(game_info.paused);
+        assert!(game_info.snapshot().paused);
+        // 일시정지 중에는 시간을 진행해도 게임 시간이 흐르지 않음
+        let logic_time = game_info.logic_time;
+        game_info.advance(5_000);
+        assert_eq!(game_info.logic_time, logic_time);
+    }
+
+    #[test]
+    fn focus_loss_is_ignored_without_auto_pause() {
+        let now = Instant::now();
+        let mut game_info = GameInfo::with_option(GameOption {
+            auto_pause: false,
+            seed: Some(1),
+            ..Default::default()
+        });
+        game_info.begin_play(now).unwrap();
+
+        game_info.on_focus_lost(now);
+
+        assert!(!game_info.paused);
+        assert!(game_info.on_play);
+    }
+
+    // 한 줄을 지울 수 있는 보드에 I를 하드드랍. 레벨 3에서 시작
+    fn hard_drop_i_at_level_three(board: &str) -> GameInfo {
+        let mut game_info = game_with(board, MinoShape::I);
+        game_info.lines_cleared_total = LINES_PER_LEVEL * 2;
+        game_info.level = 3;
+
+        game_info.hard_drop();
+
+        game_info
+    }
+
+    #[test]
+    fn clearing_the_last_line_is_a_perfect_clear() {
+        let perfect = hard_drop_i_at_level_three("GGG....GGG");
+        // 같은 한 줄을 지우지만 블럭 하나가 남음
+        let not_perfect = hard_drop_i_at_level_three("G.........\nGGG....GGG");
+
+        assert!(perfect.tetris_board.is_empty());
+        assert_eq!(perfect.record.line, 1);
+        assert_eq!(perfect.record.perfect_clear, 1);
+        assert_eq!(perfect.message.as_deref(), Some("Perfect Clear"));
+
+        assert!(!not_perfect.tetris_board.is_empty());
+        assert_eq!(not_perfect.record.line, 1);
+        assert_eq!(not_perfect.record.perfect_clear, 0);
+
+        // 퍼펙트 클리어 보너스는 지운 줄 수에 따른 점수에 레벨을 곱함
+        assert_eq!(
+            perfect.record.score - not_perfect.record.score,
+            LineClear::Single.perfect_clear_score() * 3
+        );
     }
 }
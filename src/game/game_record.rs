@@ -1,12 +1,41 @@
-#[derive(Debug, Clone, Default)]
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::{Mino, TopOut};
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GameRecord {
-    pub score: u64,         // 점수
-    pub line: u32,          // 지운 줄 개수
-    pub quad: u32,          // 4줄 지우기 개수
-    pub tspin_single: u32,  // T스핀 싱글 횟수
-    pub tspin_double: u32,  // T스핀 더블 횟수
-    pub tspin_triple: u32,  // T스핀 트리플 횟수
-    pub perfect_clear: u32, // 퍼펙트 클리어 횟수
-    pub max_combo: u32,     // 최대 콤보
-    pub back_to_back: u32,  // 최대 백투백
+    pub score: u64,                    // 점수
+    pub line: u32,                     // 지운 줄 개수
+    pub quad: u32,                     // 4줄 지우기 개수
+    pub tspin_single: u32,             // T스핀 싱글 횟수
+    pub tspin_double: u32,             // T스핀 더블 횟수
+    pub tspin_triple: u32,             // T스핀 트리플 횟수
+    pub perfect_clear: u32,            // 퍼펙트 클리어 횟수
+    pub max_combo: u32,                // 최대 콤보
+    pub back_to_back: u32,             // 최대 백투백
+    pub spawn_count: [u32; 7],         // 미노별 출현 횟수 (Mino 코드 순서: I, J, L, O, S, T, Z)
+    pub finish_time: Option<Duration>, // 목표를 달성하기까지 걸린 시간 (치즈 레이스 등)
+    #[serde(default)]
+    pub top_out: Option<TopOut>, // 게임오버 원인
+    #[serde(default)]
+    pub finesse_fault: u32, // 최소 입력 수보다 키를 더 눌러서 놓은 미노 개수
+}
+
+impl GameRecord {
+    // 미노 출현 횟수 증가
+    pub fn count_spawn(&mut self, mino: Mino) {
+        if let Some(count) = self.spawn_count.get_mut(i32::from(mino) as usize) {
+            *count += 1;
+        }
+    }
+
+    // 해당 미노의 출현 횟수
+    pub fn spawn_count_of(&self, mino: Mino) -> u32 {
+        self.spawn_count
+            .get(i32::from(mino) as usize)
+            .copied()
+            .unwrap_or(0)
+    }
 }
@@ -0,0 +1,43 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::{GameRecord, MinoShape, Point, TetrisBoard};
+
+// 스냅샷 형식 버전. 필드 구성이 바뀌면 올려서 이전 형식과 구분
+pub const GAME_SNAPSHOT_VERSION: u32 = 1;
+
+// 진행중인 게임을 저장했다가 그대로 이어서 할 수 있도록 모아둔 상태
+// 떨어지는 중인 미노와 중력/록딜레이 진행 시간까지 담아두므로 복원하면 같은 위치, 같은 타이밍에서 이어짐
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub version: u32, // 스냅샷 형식 버전 (GAME_SNAPSHOT_VERSION)
+
+    pub tetris_board: TetrisBoard, // 고정된 블럭
+    pub record: GameRecord,        // 점수와 기록
+    pub level: u32,
+    pub lines_cleared_total: u32,
+    pub combo: Option<u32>,
+    pub back2back: Option<u32>,
+    pub running_time: Duration, // 플레이 시간 (일시정지 구간 제외)
+
+    pub current_mino: Option<MinoShape>, // 떨어지는 중인 미노 (회전 상태 포함)
+    pub current_position: Point,
+    pub hold: Option<MinoShape>,
+    pub hold_used: bool,
+    pub bag: Vec<MinoShape>, // 넥스트 (앞쪽이 먼저 나옴)
+    pub rng_state: u64,      // 가방 난수 생성기 내부 상태
+    pub rng_seed: u64,
+
+    pub logic_time: u64,             // 게임 로직이 진행된 시간 (밀리초)
+    pub gravity_elapsed: u64,        // 마지막 중력 틱 이후 쌓인 시간 (밀리초)
+    pub freezed: bool,               // 고정 대기 여부
+    pub lock_delay_count: u8,        // 록딜레이 카운트
+    pub former_lock_delay_count: u8, // 마지막으로 확인한 록딜레이 카운트
+    pub last_chance_used: bool,      // 락아웃 기회 사용 여부
+    pub garbage_rng_state: u64,      // 쓰레기 줄 난수 생성기 내부 상태
+    pub garbage_elapsed: u64,        // 마지막 쓰레기 줄 이후 쌓인 시간 (밀리초)
+    pub garbage_interval: u64,       // 현재 쓰레기 줄 간격 (밀리초)
+    #[serde(default)]
+    pub pending_garbage: Vec<(u32, usize)>, // 받아두고 아직 올라오지 않은 쓰레기 줄 (줄 수, 구멍 위치)
+}
@@ -0,0 +1,20 @@
+use crate::util::format_thousands;
+
+// 화면에 보여줄 점수, 레벨, 지운 줄 수. 배치와 관계없이 같은 값을 여러 곳에 그릴 수 있도록 GameInfo에서 따로 뽑아둠
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GameStats {
+    pub score: u64,
+    pub level: u32,
+    pub lines: u32,
+}
+
+impl GameStats {
+    // 이름과 표시용 문자열 목록 (숫자는 세 자리마다 쉼표)
+    pub fn to_text_list(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("Score", format_thousands(self.score)),
+            ("Level", format_thousands(self.level as u64)),
+            ("Lines", format_thousands(self.lines as u64)),
+        ]
+    }
+}
@@ -0,0 +1,111 @@
+use std::time::Duration;
+
+use crate::game::{GameRecord, GameStats, MinoShape, Point, TetrisBoard, TetrisCell};
+use crate::options::block_style::BlockStyle;
+use crate::options::ghost_style::GhostStyle;
+use crate::options::theme::Theme;
+
+// 화면에 보여줄 게임 상태를 한 시점에 복사해둔 읽기 전용 값
+// GameInfo 잠금을 오래 잡지 않도록 잠깐 잠근 사이에 만들어두고, 그리기나 UI 갱신은 잠금을 푼 뒤 이 값으로 함
+#[derive(Debug, Clone)]
+pub struct GameView {
+    pub tetris_board: TetrisBoard,       // 고정된 블럭
+    pub current_mino: Option<MinoShape>, // 떨어지는 중인 미노
+    pub current_position: Point,
+    pub ghost_position: Option<Point>,
+    pub ghost_cells: Vec<(Point, TetrisCell)>, // 고스트가 차지하는 칸
+    pub spawn_peek_cells: Vec<(Point, bool)>,  // 다음 미노 출현 칸과 충돌 여부
+    pub clearing_rows: Vec<usize>,             // 깜빡이는 중인 지울 줄
+    pub clear_elapsed: u64,
+
+    pub stats: GameStats, // 점수, 레벨, 지운 줄 수
+    pub record: GameRecord,
+    pub combo: Option<u32>,
+    pub back2back: Option<u32>,
+    pub next: Vec<MinoShape>, // 화면에 보여줄 넥스트
+    pub hold: Option<MinoShape>,
+    pub message: Option<String>,
+    pub play_time: Duration,
+
+    pub lose: bool,
+    pub win: bool,
+    pub paused: bool,
+    pub show_help: bool,
+    pub help_lines: Vec<String>, // 조작법 도움말
+
+    pub block_style: BlockStyle,
+    pub theme: Theme,
+    pub ghost_style: GhostStyle,
+    pub show_grid: bool,
+    pub mirror: bool,
+    pub next_preview_count: usize,
+}
+
+impl GameView {
+    // 고정된 블럭 위에 떨어지는 중인 미노를 겹친 보드. 고스트가 단색이면 고스트도 함께 겹침
+    // 미노는 보드 칸에 쓰지 않고 따로 들고 있다가 그릴 때만 합치므로 이동, 줄 삭제는 고정된 블럭만 다룸
+    pub fn overlay_board(&self) -> TetrisBoard {
+        let mut tetris_board = self.tetris_board.clone();
+
+        if let Some(current_mino) = self.current_mino {
+            tetris_board.write_current_mino(current_mino.cells, self.current_position);
+
+            // 반투명 고스트는 보드를 그린 뒤 따로 덧그림
+            if let (GhostStyle::SolidColor, Some(ghost_position)) =
+                (self.ghost_style, self.ghost_position)
+            {
+                tetris_board.write_current_mino(current_mino.to_ghost().cells, ghost_position);
+            }
+        }
+
+        tetris_board
+    }
+
+    // 다음 미노 출현 미리보기를 보드에 겹침. 빈 칸은 흐리게, 쌓인 블럭과 겹치는 칸은 경고색으로 표시
+    pub fn overlay_spawn_peek(&self, tetris_board: &mut TetrisBoard) {
+        for &(point, conflict) in &self.spawn_peek_cells {
+            let cell = tetris_board
+                .cells
+                .get_mut(point.y as usize)
+                .and_then(|row| row.get_mut(point.x as usize));
+
+            if let Some(cell) = cell {
+                if conflict {
+                    *cell = TetrisCell::Warning;
+                } else if cell.is_empty() {
+                    *cell = TetrisCell::Ghost;
+                }
+            }
+        }
+    }
+
+    // 반투명 고스트 설정이라면 보드를 그린 뒤 덧그릴 고스트 칸과 색상(현재 미노 색상), 투명도
+    // 거울 모드라면 보드와 같이 좌우를 뒤집은 칸과 뒤집힌 미노의 색상
+    pub fn ghost_overlay(&self) -> Option<(Vec<Point>, TetrisCell, f64)> {
+        let alpha = match self.ghost_style {
+            GhostStyle::TransparentPieceColor { alpha } => alpha.clamp(0.0, 1.0),
+            GhostStyle::SolidColor | GhostStyle::Hidden => return None,
+        };
+
+        let &(_, cell) = self.ghost_cells.first()?;
+        let cell = if self.mirror { cell.mirrored() } else { cell };
+
+        let column_count = self.tetris_board.column_count as i64;
+        let points = self
+            .ghost_cells
+            .iter()
+            .map(|&(point, _)| {
+                if self.mirror {
+                    Point {
+                        x: column_count - 1 - point.x,
+                        y: point.y,
+                    }
+                } else {
+                    point
+                }
+            })
+            .collect();
+
+        Some((points, cell, alpha))
+    }
+}
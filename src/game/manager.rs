@@ -1,44 +1,104 @@
 use futures_util::stream::StreamExt;
 use gloo_timers::future::IntervalStream;
 use std::cell::RefCell;
-use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 
-use wasm_bindgen::prelude::Closure;
+use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 use wasm_bindgen_futures::spawn_local;
 
+use crate::constants::board::{HOLD_PANEL_ROW_COUNT, PANEL_COLUMN_COUNT};
 use crate::constants::character::SPECIAL_SPACE;
-use crate::constants::time::TICK_LOOP_INTERVAL;
+use crate::constants::time::{LINE_CLEAR_FLASH_INTERVAL, TICK_LOOP_INTERVAL};
 use crate::game::game_info::GameInfo;
-use crate::js_bind::request_animation_frame::request_animation_frame;
+use crate::game::{GameStats, GameView, TetrisCell};
 use crate::js_bind::write_text::write_text;
 use crate::options::game_option::GameOption;
+use crate::util::{format_play_time, format_thousands, lock_or_recover};
 use crate::wasm_bind;
 
 pub struct GameManager {
     pub game_info: Arc<Mutex<GameInfo>>,
 }
 
+thread_local! {
+    // 마지막으로 만든 매니저의 게임. 인자 없이 부르는 JS 바인딩이 현재 게임을 찾을 때 씀
+    static LIVE_GAME: RefCell<Option<Arc<Mutex<GameInfo>>>> = const { RefCell::new(None) };
+}
+
+// 현재 게임의 보드(고정된 블럭)를 PNG data URL로 내보냄. 만들어진 게임이 없다면 에러
+#[wasm_bindgen]
+pub fn export_board_png() -> Result<String, JsValue> {
+    let game_info = LIVE_GAME
+        .with(|live_game| live_game.borrow().clone())
+        .ok_or_else(|| JsValue::from_str("game is not created"))?;
+
+    let game_info = lock_or_recover(&game_info);
+
+    wasm_bind::render_board_png(
+        &game_info.tetris_board,
+        game_info.block_style,
+        &game_info.theme.board_border,
+        game_info.show_grid,
+        &game_info.theme.cell_palette,
+    )
+}
+
 impl GameManager {
+    // 빈 보드와 패널을 그림. 현재 게임으로 등록되지 않도록 매니저 없이 기본 설정의 게임만 만듦
     pub fn empty_render() {
-        let manager = Self::new();
-
-        let game_info = manager.game_info.lock().unwrap();
+        let game_info = GameInfo::with_option(Default::default());
         let tetris_board = game_info.tetris_board.clone();
 
-        wasm_bind::render_board(
+        if let Err(error) = wasm_bind::render_board(
             tetris_board.unfold(),
             tetris_board.board_width,
             tetris_board.board_height,
             tetris_board.column_count,
             tetris_board.row_count,
             tetris_board.hidden_row_count,
-        );
+            game_info.block_style,
+            &game_info.theme.board_border,
+            game_info.show_grid,
+            &game_info.theme.cell_palette,
+            None,
+        ) {
+            log::warn!("render_board failed: {:?}", error);
+        }
 
-        let next = game_info.bag.iter().map(|e| e.mino.into()).collect();
-        wasm_bind::render_next(next, 120, 520, 6, 26);
+        let next = game_info
+            .next_preview()
+            .iter()
+            .map(|e| e.mino.into())
+            .collect();
+        let (next_width, next_height) = wasm_bind::next_panel_size(game_info.next_preview_count);
+        if let Err(error) = wasm_bind::render_next(
+            next,
+            next_width,
+            next_height,
+            PANEL_COLUMN_COUNT as u8,
+            wasm_bind::next_panel_row_count(game_info.next_preview_count) as u8,
+            game_info.block_style,
+            &game_info.theme.next_border,
+            &game_info.theme.cell_palette,
+            game_info.mirror,
+        ) {
+            log::warn!("render_next failed: {:?}", error);
+        }
 
-        wasm_bind::render_hold(game_info.hold.map(|e| e.mino.into()), 120, 120, 6, 6);
+        let (hold_width, hold_height) = wasm_bind::hold_panel_size();
+        if let Err(error) = wasm_bind::render_hold(
+            game_info.hold.map(|e| e.mino.into()),
+            hold_width,
+            hold_height,
+            PANEL_COLUMN_COUNT as u8,
+            HOLD_PANEL_ROW_COUNT as u8,
+            game_info.block_style,
+            &game_info.theme.hold_border,
+            &game_info.theme.cell_palette,
+            game_info.mirror,
+        ) {
+            log::warn!("render_hold failed: {:?}", error);
+        }
     }
 
     pub fn new() -> Self {
@@ -49,63 +109,71 @@ impl GameManager {
         let game_info = GameInfo::with_option(option);
 
         let game_info = Arc::new(Mutex::new(game_info));
+        LIVE_GAME.with(|live_game| *live_game.borrow_mut() = Some(Arc::clone(&game_info)));
 
         Self { game_info }
     }
 
     pub fn on_play(&self) -> bool {
-        self.game_info.lock().unwrap().on_play
+        lock_or_recover(&self.game_info).on_play
     }
 
-    pub fn start_game(&self) -> Option<()> {
-        if self.on_play() {
-            return None;
-        }
+    // 일시정지 (포커스를 잃었을 때 자동 일시정지 설정이 켜져있는 경우만)
+    pub fn on_focus_lost(&self) -> Option<()> {
+        lock_or_recover(&self.game_info).on_focus_lost(instant::Instant::now());
 
-        self.game_info.lock().ok()?.on_play = true;
-        self.game_info.lock().ok()?.lose = false;
+        Some(())
+    }
+
+    // 일시정지. 중력과 입력 처리가 멈추고, 재개하면 멈춘 위치와 점수 그대로 이어서 진행
+    pub fn pause_game(&self) -> Option<()> {
+        lock_or_recover(&self.game_info).pause(instant::Instant::now());
+
+        Some(())
+    }
+
+    // 일시정지 해제
+    pub fn resume_game(&self) -> Option<()> {
+        lock_or_recover(&self.game_info).resume(instant::Instant::now());
+
+        Some(())
+    }
+
+    pub fn start_game(&self) -> Option<()> {
+        // 확인부터 시작 상태로 바꾸는 것까지 한번의 잠금 안에서 처리해서
+        // 시작 버튼이 연달아 눌려도 두 번째 호출은 이미 진행중인 게임을 보고 그냥 돌아감
+        let generation = lock_or_recover(&self.game_info).begin_play(instant::Instant::now())?;
 
         log::info!("GAME START");
 
         // tick - 중력 스레드
         let game_info = Arc::clone(&self.game_info);
-        let mut former_lock_delay_count:u8 = 0;
         spawn_local(async move {
-            // 시작 기준점
-            let mut start_point = instant::Instant::now();
+            // 마지막으로 게임 시간을 진행시킨 시점
+            let mut last_advance = instant::Instant::now();
 
             let game_info = game_info;
             let _game_info = Arc::clone(&game_info);
 
             // 기본 100밀리초 단위마다 반복해서 타임 체크 (더 세밀한 제어가 필요하다면 문제없는 선에서 낮춰도 무방)
             let mut future_list = IntervalStream::new(TICK_LOOP_INTERVAL).map(move |_| {
-                let mut game_info = game_info.lock().unwrap();
-                if former_lock_delay_count != game_info.lock_delay_count{
-                    if game_info.lock_delay_count<8 {
-                        start_point = instant::Instant::now();
-                    }
-                    former_lock_delay_count = game_info.lock_delay_count;
-                }
-                game_info.running_time += TICK_LOOP_INTERVAL as u128;
-
-                let duration = start_point.elapsed();
-
-                // tick이 발생하지 않은 시점에서 경과된 시간.
-                let elapsed_time = duration.as_millis();
+                let mut game_info = lock_or_recover(&game_info);
 
-                // 여기서 딜레이 커스텀하면 될듯
-                let delay = game_info.tick_interval as u128 + (game_info.lock_delay as u128);
+                let now = instant::Instant::now();
+                game_info.update_running_time(now);
 
-                // 지정된 딜레이만큼 지났다면 다시 초기화하고 tick 한칸 수행
-                if elapsed_time >= delay {
-                    start_point = instant::Instant::now();
-                    game_info.tick();
-                }
+                // 실제로 흐른 시간만큼 중력, DAS/ARR 진행
+                let elapsed = now.duration_since(last_advance).as_millis() as u64;
+                last_advance = now;
+                game_info.advance(elapsed);
             });
 
             let game_info = _game_info;
             loop {
-                if game_info.lock().unwrap().on_play {
+                // 잠금은 확인하는 동안만 잡고 await 전에 놓음
+                let running = lock_or_recover(&game_info).is_loop_running(generation);
+
+                if running {
                     let next = future_list.next();
                     next.await;
                 } else {
@@ -114,110 +182,218 @@ impl GameManager {
             }
         });
 
-        // 렌더링 스레드
-        let game_info = Arc::clone(&self.game_info);
-        spawn_local(async move {
-            let f = Rc::new(RefCell::new(None));
-            let g = f.clone();
+        // 렌더링 루프. 게임이 끝나거나 새 게임이 시작되면 멈춤
+        wasm_bind::run_render(
+            Arc::clone(&self.game_info),
+            move |game_info| !game_info.is_loop_running(generation),
+            Self::frame_view,
+            Self::render_frame,
+        );
 
-            *g.borrow_mut() = Some(Closure::new(move || {
-                let game_info = game_info.lock().unwrap();
+        Some(())
+    }
 
-                if !game_info.on_play {
-                    // Drop our handle to this closure so that it will get cleaned
-                    // up once we return.
-                    let _ = f.borrow_mut().take();
-                    return;
-                }
+    // 쌓인 입력을 처리하고 이번 프레임에 그릴 상태를 복사. 잠금을 잡고 있는 동안에는 이것만 함
+    // 상태가 바뀐 프레임인지도 함께 넘기고 표시는 지움
+    fn frame_view(game_info: &mut GameInfo) -> (bool, GameView) {
+        // 중력 틱을 기다리지 않도록 화면 프레임마다 쌓인 입력을 정해진 순서로 처리
+        game_info.flush_events();
 
-                let tetris_board = match game_info.current_mino {
-                    Some(current_mino) => {
-                        let mut tetris_board = game_info.tetris_board.clone();
-                        tetris_board
-                            .write_current_mino(current_mino.cells, game_info.current_position);
-
-                        let ghost_position = game_info.get_hard_drop_position().unwrap();
-                        tetris_board.write_current_mino(
-                            current_mino.clone().to_ghost().cells,
-                            ghost_position,
-                        );
-
-                        tetris_board
-                    }
-                    None => game_info.tetris_board.clone(),
-                };
-
-                wasm_bind::render_board(
-                    tetris_board.unfold(),
-                    tetris_board.board_width,
-                    tetris_board.board_height,
-                    tetris_board.column_count,
-                    tetris_board.row_count,
-                    tetris_board.hidden_row_count,
-                );
-
-                let next = game_info.bag.iter().map(|e| e.mino.into()).collect();
-                wasm_bind::render_next(next, 120, 520, 6, 26);
-
-                wasm_bind::render_hold(game_info.hold.map(|e| e.mino.into()), 120, 120, 6, 6);
-
-                write_text("score", game_info.record.score.to_string());
-                write_text("pc", game_info.record.perfect_clear.to_string());
-                write_text("quad", game_info.record.quad.to_string());
-
-                if let Some(back2back) = game_info.back2back {
-                    if back2back != 0 {
-                        write_text("back2back", format!("Back2Back {}", back2back));
-                    }
-                } else {
-                    write_text("back2back", SPECIAL_SPACE.into());
-                }
+        let dirty = std::mem::take(&mut game_info.dirty);
+        (dirty, game_info.snapshot())
+    }
 
-                if let Some(combo) = game_info.combo {
-                    if combo > 0 {
-                        write_text("combo", format!("Combo {}", combo));
-                    }
-                } else {
-                    write_text("combo", SPECIAL_SPACE.into());
-                }
+    // 한 프레임 렌더링. 보드, 넥스트, 홀드와 점수 등의 텍스트를 복사해둔 상태로 다시 그림
+    fn render_frame((dirty, view): (bool, GameView)) {
+        // 상태가 바뀐 프레임에만 캔버스를 다시 그림. 플레이 시간 등의 텍스트는 매 프레임 갱신
+        // 미노가 멈춰있는 동안에는 중력 틱(레벨 1 기준 1초)마다 한번만 그리므로 60fps 기준 초당 60회에서 1회 정도로 줄어듦
+        if dirty {
+            Self::render_canvas(&view);
+        }
 
-                if let Some(message) = game_info.message.clone() {
-                    write_text("message", message);
-                } else {
-                    write_text("message", SPECIAL_SPACE.into());
+        Self::render_stats(&view.stats);
+        write_text("pc", view.record.perfect_clear.to_string());
+        write_text("quad", view.record.quad.to_string());
+        write_text("finesse", view.record.finesse_fault.to_string());
+        write_text("time", format_play_time(view.play_time));
+
+        if let Some(back2back) = view.back2back {
+            if back2back != 0 {
+                write_text("back2back", format!("Back2Back {}", back2back));
+            }
+        } else {
+            write_text("back2back", SPECIAL_SPACE.into());
+        }
+
+        if let Some(combo) = view.combo {
+            if combo > 0 {
+                write_text("combo", format!("Combo {}", combo));
+            }
+        } else {
+            write_text("combo", SPECIAL_SPACE.into());
+        }
+
+        if let Some(message) = view.message {
+            write_text("message", message);
+        } else {
+            write_text("message", SPECIAL_SPACE.into());
+        }
+
+        if view.paused && !view.show_help {
+            write_text("pause", "Paused — click to resume".into());
+        } else {
+            write_text("pause", SPECIAL_SPACE.into());
+        }
+    }
+
+    // 보드, 고스트, 넥스트, 홀드 캔버스와 그 위의 도움말/게임 오버 화면을 현재 상태로 다시 그림
+    fn render_canvas(view: &GameView) {
+        let mut tetris_board = view.overlay_board();
+
+        // 지울 줄은 일정 간격으로 빈 칸과 번갈아 그려서 깜빡이게 함
+        if (view.clear_elapsed / LINE_CLEAR_FLASH_INTERVAL).is_multiple_of(2) {
+            for &y in &view.clearing_rows {
+                if let Some(row) = tetris_board.cells.get_mut(y) {
+                    row.fill(TetrisCell::Empty);
                 }
+            }
+        }
 
-                request_animation_frame(f.borrow().as_ref().unwrap());
-            }));
+        // 다음 미노 출현 미리보기
+        view.overlay_spawn_peek(&mut tetris_board);
 
-            request_animation_frame(g.borrow().as_ref().unwrap());
-        });
+        // 거울 모드라면 다 합친 보드를 좌우로 뒤집어서 그림
+        if view.mirror {
+            tetris_board.mirror();
+        }
 
-        Some(())
+        // 반투명 고스트는 보드를 그린 다음 같은 버퍼에 덧그림
+        let ghost = view
+            .ghost_overlay()
+            .map(|(points, cell, alpha)| wasm_bind::GhostDraw {
+                points: points
+                    .iter()
+                    .flat_map(|point| [point.x as i32, point.y as i32])
+                    .collect(),
+                color_code: cell.into_code(),
+                alpha,
+            });
+
+        if let Err(error) = wasm_bind::render_board(
+            tetris_board.unfold(),
+            tetris_board.board_width,
+            tetris_board.board_height,
+            tetris_board.column_count,
+            tetris_board.row_count,
+            tetris_board.hidden_row_count,
+            view.block_style,
+            &view.theme.board_border,
+            view.show_grid,
+            &view.theme.cell_palette,
+            ghost,
+        ) {
+            log::warn!("render_board failed: {:?}", error);
+        }
+
+        let next = view.next.iter().map(|e| e.mino.into()).collect();
+        let (next_width, next_height) = wasm_bind::next_panel_size(view.next_preview_count);
+        if let Err(error) = wasm_bind::render_next(
+            next,
+            next_width,
+            next_height,
+            PANEL_COLUMN_COUNT as u8,
+            wasm_bind::next_panel_row_count(view.next_preview_count) as u8,
+            view.block_style,
+            &view.theme.next_border,
+            &view.theme.cell_palette,
+            view.mirror,
+        ) {
+            log::warn!("render_next failed: {:?}", error);
+        }
+
+        let (hold_width, hold_height) = wasm_bind::hold_panel_size();
+        if let Err(error) = wasm_bind::render_hold(
+            view.hold.map(|e| e.mino.into()),
+            hold_width,
+            hold_height,
+            PANEL_COLUMN_COUNT as u8,
+            HOLD_PANEL_ROW_COUNT as u8,
+            view.block_style,
+            &view.theme.hold_border,
+            &view.theme.cell_palette,
+            view.mirror,
+        ) {
+            log::warn!("render_hold failed: {:?}", error);
+        }
+
+        // 도움말은 보드 위에 덮어 그림 (도움말이 떠있는 동안에도 일시정지 상태)
+        if view.show_help {
+            if let Err(error) = wasm_bind::render_help(view.help_lines.clone()) {
+                log::warn!("render_help failed: {:?}", error);
+            }
+        } else if view.lose {
+            let score = format_thousands(view.record.score);
+            if let Err(error) = wasm_bind::render_game_over(score) {
+                log::warn!("render_game_over failed: {:?}", error);
+            }
+        }
+    }
+
+    // 점수, 레벨, 지운 줄 수를 같은 이름(소문자)의 id를 가진 요소에 씀
+    fn render_stats(stats: &GameStats) {
+        for (name, text) in stats.to_text_list() {
+            write_text(&name.to_lowercase(), text);
+        }
     }
 
     pub fn end_game(&self) -> Option<()> {
-        self.game_info.lock().ok()?.on_play = false;
+        lock_or_recover(&self.game_info).end_play(instant::Instant::now());
 
         Some(())
     }
 
+    // 진행중인 게임을 끝내고 처음 상태로 새로 시작. 이전 판의 틱/렌더링 루프는 세대가 바뀌어 멈춤
+    pub fn restart_game(&self) -> Option<()> {
+        {
+            let mut game_info = lock_or_recover(&self.game_info);
+            game_info.end_play(instant::Instant::now());
+            game_info.init_game()?;
+        }
 
-    pub fn init_running_time(&self) -> Option<()> {
-        let mut game_info = self.game_info.lock().ok().unwrap();
-        game_info.running_time = 0;
-        Some(())
+        self.start_game()
     }
 
+    pub fn init_running_time(&self) -> Option<()> {
+        let mut game_info = lock_or_recover(&self.game_info);
+        game_info.init_running_time()
+    }
 
     // 보드 초기화
 
     // 컨텍스트 초기화
 
-
     // 가방 초기화
 
-
     // 점수 초기화
+}
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen_test]
+    fn exports_the_current_board_as_png() {
+        let manager = GameManager::new();
+        lock_or_recover(&manager.game_info)
+            .load_board_ascii("GGG....GGG\nGGGGGGGG.G")
+            .unwrap();
+
+        let data_url = export_board_png().unwrap();
+
+        assert!(data_url.starts_with("data:image/png;base64,"));
+        assert!(data_url.len() > "data:image/png;base64,".len());
+    }
 }
@@ -1,11 +1,15 @@
+use serde::{Deserialize, Serialize};
+
 use crate::game::tetris_cell::TetrisCell;
+use crate::game::Point;
+use crate::util::rotate_right;
 
 use super::colors::{
     I_DEFAULT_COLOR, J_DEFAULT_COLOR, L_DEFAULT_COLOR, O_DEFAULT_COLOR, S_DEFAULT_COLOR,
     T_DEFAULT_COLOR, Z_DEFAULT_COLOR,
 };
 
-#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub enum Mino {
     I,
     J,
@@ -18,6 +22,19 @@ pub enum Mino {
     ETC,
 }
 
+impl Mino {
+    // 좌우를 뒤집었을 때 같은 모양이 되는 미노. S와 Z, J와 L이 서로 바뀌고 나머지는 그대로
+    pub fn mirrored(self) -> Self {
+        match self {
+            Mino::S => Mino::Z,
+            Mino::Z => Mino::S,
+            Mino::J => Mino::L,
+            Mino::L => Mino::J,
+            mino => mino,
+        }
+    }
+}
+
 impl From<Mino> for i32 {
     fn from(value: Mino) -> Self {
         match value {
@@ -48,7 +65,7 @@ impl From<i32> for Mino {
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct MinoShape {
     pub mino: Mino,
     pub cells: MinoShapeCells,
@@ -57,6 +74,21 @@ pub struct MinoShape {
 
 pub type MinoShapeCells = [[TetrisCell; 4]; 4];
 
+// 미노 칸 배열의 왼쪽 위를 origin에 놓았을 때 블럭이 차지하는 보드 좌표
+pub fn occupied_points(cells: &MinoShapeCells, origin: Point) -> Vec<Point> {
+    let mut points = vec![];
+
+    for (mino_y, row) in cells.iter().enumerate() {
+        for (mino_x, cell) in row.iter().enumerate() {
+            if !cell.is_empty() {
+                points.push(origin.move_xy(mino_x as i64, mino_y as i64));
+            }
+        }
+    }
+
+    points
+}
+
 impl From<i32> for MinoShape {
     fn from(value: i32) -> Self {
         match value {
@@ -74,6 +106,86 @@ impl From<i32> for MinoShape {
 }
 
 impl MinoShape {
+    // 기본 7종 미노 목록
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::I,
+            Self::L,
+            Self::J,
+            Self::S,
+            Self::Z,
+            Self::O,
+            Self::T,
+        ]
+    }
+
+    // 회전할 때 기준이 되는 정사각형 영역의 한 변 길이. I는 4x4, 나머지는 3x3 (O는 회전하지 않음)
+    pub fn rotation_length(&self) -> usize {
+        if self.mino == Mino::I {
+            4
+        } else {
+            3
+        }
+    }
+
+    // 출현 방향부터 시계방향으로 0, R, 2, L 네 방향의 모양 (SRS 기준)
+    // O는 네 방향 모두 같고, I/S/Z는 상자 안 위치만 다를 뿐 모양은 두 가지
+    pub fn orientations(&self) -> [Self; 4] {
+        let mut shape = MinoShape::from(i32::from(self.mino));
+        let mut orientations = [shape; 4];
+
+        for (rotation_count, orientation) in orientations.iter_mut().enumerate() {
+            *orientation = shape;
+            orientation.rotation_count = rotation_count;
+
+            if self.mino != Mino::O {
+                rotate_right(&mut shape.cells, self.rotation_length());
+            }
+        }
+
+        orientations
+    }
+
+    // 위치를 무시했을 때 서로 다른 모양의 방향 개수
+    pub fn distinct_orientation_count(&self) -> usize {
+        match self.mino {
+            Mino::O => 1,
+            Mino::I | Mino::S | Mino::Z => 2,
+            _ => 4,
+        }
+    }
+
+    // 4x4 영역의 왼쪽 위를 origin에 놓았을 때 블럭이 차지하는 보드 좌표 (위 행부터, 행 안에서는 왼쪽부터)
+    pub fn occupied_points(&self, origin: Point) -> Vec<Point> {
+        occupied_points(&self.cells, origin)
+    }
+
+    // 4x4 영역을 x열에 놓았을 때 모든 블럭이 보드 좌우 안에 들어오는지
+    pub fn fits_columns(&self, x: i64, column_count: u32) -> bool {
+        self.cells.iter().all(|row| {
+            row.iter().enumerate().all(|(dx, cell)| {
+                let column = x + dx as i64;
+                cell.is_empty() || (column >= 0 && column < column_count as i64)
+            })
+        })
+    }
+
+    // 4x4 영역 안에서 좌우를 뒤집은 모양 (거울 모드 화면 표시용)
+    // 뒤집힌 S는 Z 모양이므로 미노와 블럭 색도 Z로 바꿔서 보드에 뒤집어 그린 미노와 같게 보이게 함
+    pub fn mirrored(mut self) -> Self {
+        self.mino = self.mino.mirrored();
+
+        for row in &mut self.cells {
+            row.reverse();
+
+            for cell in row.iter_mut() {
+                *cell = cell.mirrored();
+            }
+        }
+
+        self
+    }
+
     pub fn to_ghost(mut self) -> Self {
         for row in &mut self.cells {
             for cell in row {
@@ -375,3 +487,137 @@ impl MinoShape {
         ],
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 회전 기준 영역(I는 4x4, 나머지는 3x3)만 잘라서 "행/행/행" 형태로 나타냄. 블럭은 '#'
+    fn box_rows(shape: &MinoShape) -> String {
+        let length = shape.rotation_length();
+
+        shape.cells[..length]
+            .iter()
+            .map(|row| {
+                row[..length]
+                    .iter()
+                    .map(|cell| if cell.is_empty() { '.' } else { '#' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    // 블럭이 차지하는 좌표를 가장 왼쪽 위 기준으로 옮겨서 4x4 영역 안의 위치 차이를 없앰
+    fn normalized_points(shape: &MinoShape) -> Vec<Point> {
+        let points = shape.occupied_points(Point::default());
+        let min_x = points.iter().map(|point| point.x).min().unwrap();
+        let min_y = points.iter().map(|point| point.y).min().unwrap();
+
+        points
+            .iter()
+            .map(|point| point.move_xy(-min_x, -min_y))
+            .collect()
+    }
+
+    #[test]
+    fn orientations_match_the_guideline() {
+        // 가이드라인(SRS)의 0, R, 2, L 상태
+        let guideline = [
+            (
+                MinoShape::I,
+                [
+                    "..../####/..../....",
+                    "..#./..#./..#./..#.",
+                    "..../..../####/....",
+                    ".#../.#../.#../.#..",
+                ],
+            ),
+            (
+                MinoShape::J,
+                ["#../###/...", ".##/.#./.#.", ".../###/..#", ".#./.#./##."],
+            ),
+            (
+                MinoShape::L,
+                ["..#/###/...", ".#./.#./.##", ".../###/#..", "##./.#./.#."],
+            ),
+            (
+                MinoShape::S,
+                [".##/##./...", ".#./.##/..#", ".../.##/##.", "#../##./.#."],
+            ),
+            (
+                MinoShape::Z,
+                ["##./.##/...", "..#/.##/.#.", ".../##./.##", ".#./##./#.."],
+            ),
+            (
+                MinoShape::T,
+                [".#./###/...", ".#./.##/.#.", ".../###/.#.", ".#./##./.#."],
+            ),
+        ];
+
+        for (mino, expected) in guideline {
+            let orientations = mino.orientations();
+
+            for (rotation_count, orientation) in orientations.iter().enumerate() {
+                assert_eq!(orientation.rotation_count, rotation_count);
+                assert_eq!(
+                    box_rows(orientation),
+                    expected[rotation_count],
+                    "{:?} {}",
+                    mino.mino,
+                    rotation_count
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn o_keeps_its_shape_in_every_orientation() {
+        for orientation in MinoShape::O.orientations() {
+            assert_eq!(box_rows(&orientation), ".##/.##/...");
+        }
+    }
+
+    #[test]
+    fn distinct_orientation_count_ignores_position() {
+        for mino in MinoShape::all() {
+            let mut shapes: Vec<Vec<Point>> = vec![];
+
+            for orientation in mino.orientations() {
+                let shape = normalized_points(&orientation);
+
+                if !shapes.contains(&shape) {
+                    shapes.push(shape);
+                }
+            }
+
+            assert_eq!(
+                shapes.len(),
+                mino.distinct_orientation_count(),
+                "{:?}",
+                mino.mino
+            );
+        }
+    }
+
+    #[test]
+    fn mirrored_s_and_j_look_like_z_and_l() {
+        for (mino, mirrored) in [
+            (MinoShape::S, MinoShape::Z),
+            (MinoShape::Z, MinoShape::S),
+            (MinoShape::J, MinoShape::L),
+            (MinoShape::L, MinoShape::J),
+            (MinoShape::T, MinoShape::T),
+        ] {
+            let shape = mino.mirrored();
+
+            assert_eq!(shape.mino, mirrored.mino);
+            assert_eq!(normalized_points(&shape), normalized_points(&mirrored));
+            assert!(shape
+                .cells
+                .iter()
+                .flatten()
+                .all(|cell| cell.is_empty() || *cell == mirrored.cells[1][1]));
+        }
+    }
+}
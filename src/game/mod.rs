@@ -1,3 +1,6 @@
+pub mod auto_shift;
+pub use auto_shift::*;
+
 pub mod bag;
 pub use bag::*;
 
@@ -13,15 +16,44 @@ pub use game_info::*;
 pub mod game_record;
 pub use game_record::*;
 
+pub mod game_stats;
+pub use game_stats::*;
+
+pub mod game_snapshot;
+pub use game_snapshot::*;
+
+pub mod game_view;
+pub use game_view::*;
+
+#[cfg(target_arch = "wasm32")]
 pub mod manager;
+#[cfg(target_arch = "wasm32")]
 pub use manager::*;
 
+pub mod placement_error;
+pub use placement_error::*;
+
 pub mod point;
 pub use point::*;
 
+pub mod replay;
+pub use replay::*;
+
+pub mod rotate_direction;
+pub use rotate_direction::*;
+
+pub mod sequence_state;
+pub use sequence_state::*;
+
 pub mod spin_type;
 pub use spin_type::*;
 
+pub mod tetris_error;
+pub use tetris_error::*;
+
+pub mod top_out;
+pub use top_out::*;
+
 pub mod minos;
 pub use minos::*;
 
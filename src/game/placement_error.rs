@@ -0,0 +1,9 @@
+// 미노를 놓을 수 없는 이유. 벽차기 위치를 고르거나 디버깅할 때 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementError {
+    OutOfLeft,    // 보드 왼쪽 밖으로 나감
+    OutOfRight,   // 보드 오른쪽 밖으로 나감
+    OutOfBottom,  // 보드 아래로 나감
+    BlockedAbove, // 출현 영역이 막혀있어 보드 위로 나갈 수 없음
+    Overlap,      // 고정된 블럭과 겹침
+}
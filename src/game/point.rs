@@ -1,13 +1,17 @@
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Point {
     pub x: i64,
     pub y: i64,
 }
 
 impl Point {
-    pub fn start_point(column_count: u32) -> Self {
-        let y = 2;
-        let x = column_count as i64 / 2 - 2;
+    // 미노 출현 위치. spawn_row는 미노 4x4 영역의 맨 위 행
+    // 기본은 가운데 (홀수 폭이면 왼쪽으로 치우침), column_offset만큼 좌우로 옮김
+    pub fn start_point(column_count: u32, spawn_row: u32, column_offset: i32) -> Self {
+        let y = spawn_row as i64;
+        let x = column_count as i64 / 2 - 2 + column_offset as i64;
 
         Self { x, y }
     }
@@ -27,5 +31,4 @@ impl Point {
         self.y = self.y + y;
         self
     }
-
 }
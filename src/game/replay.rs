@@ -0,0 +1,125 @@
+use crate::game::{Event, GameInfo};
+use crate::options::game_option::GameOption;
+
+// 게임 진행 시간(밀리초)과 함께 기록된 입력
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedEvent {
+    pub time: u64, // 입력이 들어온 시점의 게임 진행 시간 (밀리초)
+    pub event: Event,
+}
+
+// 실제 시간 기준 리플레이. 입력 순서뿐 아니라 입력 사이의 시간까지 재현하므로 DAS/ARR, 록딜레이 타이밍도 그대로 재현됨
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RealtimeReplay {
+    pub seed: u64,               // 가방 난수 시드
+    pub events: Vec<TimedEvent>, // 시간순 입력 목록
+    pub end_time: u64,           // 기록이 끝난 시점의 게임 진행 시간 (밀리초)
+    pub board_hash: u64,         // 기록이 끝난 시점의 보드 해시 (TetrisBoard::board_hash)
+    pub score: u64,              // 기록이 끝난 시점의 점수
+}
+
+impl RealtimeReplay {
+    // 같은 옵션으로 새 게임을 만들고, 기록된 시간에 맞춰 중력을 진행시키며 입력을 다시 적용
+    pub fn play(&self, option: GameOption) -> GameInfo {
+        let mut game_info = GameInfo::with_option(option);
+        game_info.reseed(self.seed);
+        game_info.on_play = true;
+
+        for timed_event in &self.events {
+            game_info.advance(timed_event.time.saturating_sub(game_info.logic_time));
+            game_info.apply_event(timed_event.event);
+        }
+
+        game_info.advance(self.end_time.saturating_sub(game_info.logic_time));
+
+        game_info
+    }
+
+    // 같은 옵션으로 다시 재생했을 때 기록된 보드와 점수가 그대로 나오는지 확인
+    pub fn verify(&self, option: GameOption) -> bool {
+        let game_info = self.play(option);
+
+        game_info.tetris_board.board_hash() == self.board_hash
+            && game_info.record.score == self.score
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option() -> GameOption {
+        GameOption {
+            seed: Some(7),
+            ..Default::default()
+        }
+    }
+
+    // 입력 사이 시간을 일부러 들쭉날쭉하게 둬서 DAS, 록딜레이, 중력 타이밍이 모두 섞이게 함
+    fn play_live() -> GameInfo {
+        let script = [
+            (120, Event::LeftMove),
+            (400, Event::LeftRelease),
+            (50, Event::RightRotate),
+            (700, Event::HardDrop),
+            (90, Event::RightMove),
+            (30, Event::RightRelease),
+            (250, Event::SoftDrop),
+            (600, Event::SoftDropRelease),
+            (40, Event::LeftRotate),
+            (1_300, Event::HardDrop),
+            (200, Event::Hold),
+            (350, Event::RightMove),
+            (500, Event::RightRelease),
+            (10, Event::HardDrop),
+        ];
+
+        let mut game_info = GameInfo::with_option(option());
+        game_info.on_play = true;
+
+        for _ in 0..4 {
+            for (elapsed, event) in script {
+                game_info.advance(elapsed);
+                game_info.apply_event(event);
+            }
+        }
+        game_info.advance(800);
+
+        game_info
+    }
+
+    #[test]
+    fn realtime_replay_reproduces_the_lock_positions() {
+        let live = play_live();
+        assert!(!live.tetris_board.is_empty());
+        assert!(!live.lose);
+
+        let replayed = live.realtime_replay().play(option());
+
+        assert_eq!(
+            replayed.tetris_board.to_ascii(),
+            live.tetris_board.to_ascii()
+        );
+        assert_eq!(replayed.current_position, live.current_position);
+        assert_eq!(replayed.logic_time, live.logic_time);
+    }
+
+    #[test]
+    fn recorded_game_replays_to_the_same_board_hash() {
+        let live = play_live();
+        let replay = live.realtime_replay();
+
+        assert_eq!(
+            replay.play(option()).tetris_board.board_hash(),
+            live.tetris_board.board_hash()
+        );
+        assert!(replay.verify(option()));
+
+        // 기록과 다른 보드라면 검증 실패
+        let tampered = RealtimeReplay {
+            board_hash: replay.board_hash ^ 1,
+            ..replay
+        };
+        assert!(!tampered.verify(option()));
+    }
+}
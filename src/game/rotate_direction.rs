@@ -0,0 +1,6 @@
+// 회전 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RotateDirection {
+    Clockwise,        // 시계방향 (오른쪽 회전)
+    CounterClockwise, // 반시계방향 (왼쪽 회전)
+}
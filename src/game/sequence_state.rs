@@ -0,0 +1,48 @@
+use crate::game::{Mino, TetrisError};
+
+// 앞으로 나올 미노 순서를 그대로 이어가기 위한 상태 (난수 생성기 내부 상태 + 현재 가방 내용)
+// 받은 쪽에서 복원하면 이후 나오는 미노 순서가 완전히 같음
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceState {
+    pub rng_state: u64, // 난수 생성기 내부 상태
+    pub bag: Vec<Mino>, // 가방에 남아있는 미노 (앞쪽이 먼저 나옴)
+}
+
+impl SequenceState {
+    // "난수상태:미노코드,미노코드,..." 형태의 공유용 문자열
+    pub fn to_share_string(&self) -> String {
+        let bag = self
+            .bag
+            .iter()
+            .map(|mino| i32::from(*mino).to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("{}:{}", self.rng_state, bag)
+    }
+
+    pub fn from_share_string(text: &str) -> Result<Self, TetrisError> {
+        let (rng_state, bag) = text
+            .trim()
+            .split_once(':')
+            .ok_or_else(|| TetrisError::ParseError("missing ':' separator".into()))?;
+
+        let rng_state = rng_state
+            .parse::<u64>()
+            .map_err(|_| TetrisError::ParseError(format!("invalid rng state: {}", rng_state)))?;
+
+        let bag = bag
+            .split(',')
+            .filter(|code| !code.is_empty())
+            .map(|code| match code.parse::<i32>().map(Mino::from) {
+                Ok(Mino::ETC) | Err(_) => Err(TetrisError::ParseError(format!(
+                    "invalid mino code: {}",
+                    code
+                ))),
+                Ok(mino) => Ok(mino),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { rng_state, bag })
+    }
+}
@@ -0,0 +1,25 @@
+use std::fmt;
+
+// 게임 로직에서 발생하는 오류
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TetrisError {
+    InvalidCellCode(i32),  // 셀 코드로 변환할 수 없는 값
+    InvalidDimensions,     // 보드 크기와 셀 개수가 맞지 않음
+    EmptyPieceList,        // 가방을 채울 미노 목록이 비어있음
+    ParseError(String),    // 문자열 등을 해석하지 못함
+    InvalidOption(String), // 게임 옵션 값이 잘못됨 (필드 이름과 이유)
+}
+
+impl fmt::Display for TetrisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCellCode(code) => write!(f, "invalid cell code: {}", code),
+            Self::InvalidDimensions => write!(f, "invalid board dimensions"),
+            Self::EmptyPieceList => write!(f, "piece list is empty"),
+            Self::ParseError(message) => write!(f, "parse error: {}", message),
+            Self::InvalidOption(message) => write!(f, "invalid option: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for TetrisError {}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+// 게임오버(탑아웃) 원인
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TopOut {
+    BlockOut, // 출현 위치가 쌓인 블럭과 겹쳐서 새 미노가 나올 수 없음
+    LockOut,  // 미노가 보이는 영역 위(숨겨진 행)에서만 고정됨
+    PushOut,  // 아래에서 올라온 쓰레기 줄에 블럭이나 현재 미노가 보드 밖으로 밀려남
+}
@@ -1,4 +1,3 @@
-pub mod body;
 pub mod document;
 pub mod focus;
 pub mod request_animation_frame;
@@ -1,12 +1,20 @@
+// 게임 로직은 어느 타겟에서나 컴파일되고, 캔버스/DOM을 다루는 화면 쪽만 wasm32 전용
+#[cfg(target_arch = "wasm32")]
 pub mod components;
 pub mod constants;
 pub mod game;
+#[cfg(target_arch = "wasm32")]
 pub mod js_bind;
 pub mod options;
 pub mod util;
 pub mod wasm_bind;
 
+#[cfg(target_arch = "wasm32")]
 fn main() {
     wasm_logger::init(wasm_logger::Config::default());
     yew::start_app::<components::main::MainComponent>();
 }
+
+// 화면이 없는 네이티브 빌드는 게임 로직 테스트용
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {}
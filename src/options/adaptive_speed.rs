@@ -0,0 +1,51 @@
+// 보드가 찬 정도에 따라 틱 간격을 조절하는 설정
+// 비어있을 때는 빠르게(압박), 가득 찼을 때는 느리게(자비) 해서 그 사이는 선형으로 보간
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveSpeed {
+    pub empty_factor: f64, // 보드가 비어있을 때의 틱 간격 배율 (1보다 작으면 빨라짐)
+    pub full_factor: f64,  // 보드가 가득 찼을 때의 틱 간격 배율 (1보다 크면 느려짐)
+}
+
+impl AdaptiveSpeed {
+    // 기본 틱 간격과 보드 채움 비율로 실제 틱 간격 계산
+    pub fn tick_interval(&self, base_tick_interval: u64, fill_ratio: f64) -> u64 {
+        let fill_ratio = fill_ratio.clamp(0.0, 1.0);
+        let factor = self.empty_factor + (self.full_factor - self.empty_factor) * fill_ratio;
+
+        (base_tick_interval as f64 * factor).round().max(0.0) as u64
+    }
+}
+
+impl Default for AdaptiveSpeed {
+    fn default() -> Self {
+        Self {
+            empty_factor: 0.8,
+            full_factor: 1.5,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearly_full_board_ticks_slower_than_an_empty_one() {
+        let adaptive_speed = AdaptiveSpeed::default();
+
+        let empty = adaptive_speed.tick_interval(1000, 0.0);
+        let nearly_full = adaptive_speed.tick_interval(1000, 0.9);
+
+        assert_eq!(empty, 800);
+        assert_eq!(nearly_full, 1430);
+        assert!(nearly_full > empty);
+    }
+
+    #[test]
+    fn fill_ratio_outside_the_range_is_clamped() {
+        let adaptive_speed = AdaptiveSpeed::default();
+
+        assert_eq!(adaptive_speed.tick_interval(1000, -1.0), 800);
+        assert_eq!(adaptive_speed.tick_interval(1000, 2.0), 1500);
+    }
+}
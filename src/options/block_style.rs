@@ -0,0 +1,58 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+// 블럭 하나를 그릴 때의 테두리 설정
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockStyle {
+    pub stroke_width: f64, // 테두리 두께 (px)
+    pub draw_stroke: bool, // 테두리를 그릴지 여부. false면 테두리 없는 블럭
+    pub draw_glyph: bool,  // 미노 종류를 나타내는 글자를 블럭 안에 그릴지 여부 (색각 이상 대응)
+}
+
+impl Default for BlockStyle {
+    fn default() -> Self {
+        Self {
+            stroke_width: 1.0,
+            draw_stroke: true,
+            draw_glyph: false,
+        }
+    }
+}
+
+impl BlockStyle {
+    // 실제로 그릴 테두리 두께. 테두리를 끄거나 두께가 0 이하라면 None (테두리 없는 블럭)
+    pub fn stroke(&self) -> Option<f64> {
+        (self.draw_stroke && self.stroke_width > 0.0).then_some(self.stroke_width)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_style_draws_the_stroke() {
+        assert_eq!(BlockStyle::default().stroke(), Some(1.0));
+    }
+
+    #[test]
+    fn turning_off_the_stroke_gives_borderless_blocks() {
+        let style = BlockStyle {
+            stroke_width: 3.0,
+            draw_stroke: false,
+            ..Default::default()
+        };
+
+        assert_eq!(style.stroke(), None);
+    }
+
+    #[test]
+    fn zero_width_stroke_is_not_drawn() {
+        let style = BlockStyle {
+            stroke_width: 0.0,
+            ..Default::default()
+        };
+
+        assert_eq!(style.stroke(), None);
+    }
+}
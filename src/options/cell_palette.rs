@@ -0,0 +1,76 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::game::TetrisCell;
+
+// 셀 종류별 블럭 색상. 기본값은 TetrisCell::to_color와 같음
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellPalette {
+    pub empty: String,
+    pub ghost: String,
+    pub red: String,
+    pub green: String,
+    pub blue: String,
+    pub purple: String,
+    pub cyan: String,
+    pub orange: String,
+    pub yellow: String,
+    pub warning: String, // 다음 미노 출현 미리보기에서 충돌하는 칸
+    pub garbage: String, // 아래에서 올라오는 쓰레기 줄
+}
+
+impl Default for CellPalette {
+    fn default() -> Self {
+        let color = |cell: TetrisCell| cell.to_color().to_string();
+
+        Self {
+            empty: color(TetrisCell::Empty),
+            ghost: color(TetrisCell::Ghost),
+            red: color(TetrisCell::Red),
+            green: color(TetrisCell::Green),
+            blue: color(TetrisCell::Blue),
+            purple: color(TetrisCell::Purple),
+            cyan: color(TetrisCell::Cyan),
+            orange: color(TetrisCell::Orange),
+            yellow: color(TetrisCell::Yellow),
+            warning: color(TetrisCell::Warning),
+            garbage: color(TetrisCell::Garbage),
+        }
+    }
+}
+
+impl CellPalette {
+    // 색각 이상이 있어도 구분하기 쉬운 고대비 색상 (Okabe-Ito 팔레트 기반)
+    pub fn high_contrast() -> Self {
+        Self {
+            empty: "white".into(),
+            ghost: "#bbbbbb".into(),
+            red: "#d55e00".into(),
+            green: "#009e73".into(),
+            blue: "#0072b2".into(),
+            purple: "#cc79a7".into(),
+            cyan: "#56b4e9".into(),
+            orange: "#e69f00".into(),
+            yellow: "#f0e442".into(),
+            warning: "#ff7f7f".into(),
+            garbage: "#555555".into(),
+        }
+    }
+
+    pub fn color(&self, cell: TetrisCell) -> &str {
+        match cell {
+            TetrisCell::Empty => &self.empty,
+            TetrisCell::Ghost => &self.ghost,
+            TetrisCell::Red => &self.red,
+            TetrisCell::Green => &self.green,
+            TetrisCell::Blue => &self.blue,
+            TetrisCell::Purple => &self.purple,
+            TetrisCell::Cyan => &self.cyan,
+            TetrisCell::Orange => &self.orange,
+            TetrisCell::Yellow => &self.yellow,
+            TetrisCell::Warning => &self.warning,
+            TetrisCell::Garbage => &self.garbage,
+            _ => &self.empty,
+        }
+    }
+}
@@ -0,0 +1,16 @@
+// 미리 깔린 쓰레기 줄을 모두 지우면 승리하는 치즈 레이스 설정
+// 쓰레기 블럭이 하나라도 남아있는 줄은 남은 줄로 셈 (일부만 깎인 줄도 완전히 지워야 함)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheeseRace {
+    pub garbage_rows: u32, // 시작할 때 깔아둘 쓰레기 줄 수
+    pub seed: u64,         // 구멍 위치를 정하는 난수 시드
+}
+
+impl Default for CheeseRace {
+    fn default() -> Self {
+        Self {
+            garbage_rows: 10,
+            seed: 0,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// 고정된 블럭이 일정 시간 뒤 보이지 않게 되는 모드 설정
+// 보이지 않을 뿐 보드에는 그대로 남아있으므로 충돌과 줄 삭제는 평소와 같음
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FadingBlocks {
+    pub fade_delay: u64, // 블럭이 고정된 뒤 사라지기까지의 시간 (밀리초)
+}
+
+impl Default for FadingBlocks {
+    fn default() -> Self {
+        Self { fade_delay: 3000 }
+    }
+}
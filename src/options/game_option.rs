@@ -1,11 +1,47 @@
+use crate::constants::board::HIDDEN_ROW_COUNT;
 use crate::game::bag::BagType;
+use crate::game::MinoShape;
+use crate::options::adaptive_speed::AdaptiveSpeed;
+use crate::options::block_style::BlockStyle;
+use crate::options::cheese_race::CheeseRace;
+use crate::options::fading_blocks::FadingBlocks;
+use crate::options::garbage_challenge::GarbageChallenge;
+use crate::options::ghost_style::GhostStyle;
+use crate::options::key_bindings::KeyBindings;
+use crate::options::lock_delay_policy::LockDelayPolicy;
+use crate::options::theme::Theme;
 
 pub struct GameOption {
     pub board_width: u32,
     pub board_height: u32,
     pub column_count: u32,
     pub row_count: u32,
+    pub hidden_row_count: u32, // 보이는 행 위에 추가되는 숨겨진 행 개수 (미노 출현 공간)
     pub bag_mode: BagType,
+    pub seed: Option<u64>,         // 가방 난수 시드 (None이면 무작위)
+    pub next_buffer_count: usize,  // 가방에 미리 만들어둘 넥스트 개수
+    pub next_preview_count: usize, // 화면에 보여줄 넥스트 개수
+    pub mino_list: Vec<MinoShape>, // 출현할 미노 목록 (연습 모드에서는 일부만 지정)
+    pub lock_out_leniency: bool,   // 락아웃 시 한번 더 아래로 옮겨주는 관대한 규칙 (기본은 엄격)
+    pub block_style: BlockStyle,   // 블럭 테두리 렌더링 설정
+    pub theme: Theme,              // 패널 테두리 등 화면 테마
+    pub ghost_style: GhostStyle,   // 고스트 렌더링 방식 (기본은 단색)
+    pub show_grid: bool,           // 보드에 칸 격자선 표시 (기본은 끔)
+    pub mirror: bool,              // 화면과 좌우 입력을 뒤집는 거울 모드 (기본은 끔)
+    pub line_clear_delay: u32,     // 지울 줄이 깜빡이는 시간 (밀리초). 0이면 연출 없이 바로 지움
+    pub instant_respawn: bool,     // 줄 삭제 직후 바로 다음 미노 출현 (기본은 다음 틱까지 대기)
+    pub spawn_peek: bool,          // 다음 미노 출현 위치 미리보기 (고급 옵션)
+    pub spawn_row: u32,            // 미노 출현 행 (기본은 숨겨진 행 안쪽)
+    pub spawn_column_offset: i32,  // 가운데 출현 열에서 옮길 칸 수 (음수면 왼쪽, 기본은 0)
+    pub auto_pause: bool,          // 창이 포커스를 잃으면 자동으로 일시정지
+    pub das: u32,                  // 좌우 이동키를 누른 뒤 자동 이동이 시작되기까지의 시간 (밀리초)
+    pub arr: u32,                  // 자동 이동 반복 간격 (밀리초). 0이면 벽까지 바로 이동
+    pub adaptive_speed: Option<AdaptiveSpeed>, // 보드가 찬 정도에 따른 속도 조절. None이면 사용 안함
+    pub garbage_challenge: Option<GarbageChallenge>, // 무작위 쓰레기 줄 챌린지. None이면 사용 안함
+    pub cheese_race: Option<CheeseRace>, // 쓰레기 줄을 모두 지우면 승리하는 모드. None이면 사용 안함
+    pub fading_blocks: Option<FadingBlocks>, // 고정된 블럭이 사라지는 모드. None이면 사용 안함
+    pub lock_delay_policy: LockDelayPolicy, // 고정 타이머 초기화 규칙 (기본은 8번까지 이동 시 초기화)
+    pub key_bindings: KeyBindings,          // 키 설정
 }
 
 impl Default for GameOption {
@@ -13,9 +49,34 @@ impl Default for GameOption {
         Self {
             column_count: 10,
             row_count: 20,
+            hidden_row_count: HIDDEN_ROW_COUNT,
             bag_mode: BagType::SevenBag,
+            seed: None,
+            next_buffer_count: 5,
+            next_preview_count: 5,
             board_width: 300,
             board_height: 600,
+            mino_list: MinoShape::all(),
+            lock_out_leniency: false,
+            block_style: Default::default(),
+            theme: Default::default(),
+            ghost_style: Default::default(),
+            show_grid: false,
+            mirror: false,
+            spawn_peek: false,
+            spawn_row: 2,
+            spawn_column_offset: 0,
+            auto_pause: true,
+            das: 300,
+            arr: 0,
+            instant_respawn: false,
+            line_clear_delay: 200,
+            adaptive_speed: None,
+            garbage_challenge: None,
+            cheese_race: None,
+            fading_blocks: None,
+            lock_delay_policy: Default::default(),
+            key_bindings: Default::default(),
         }
     }
 }
@@ -0,0 +1,293 @@
+use crate::constants::time::MAX_INPUT_DELAY;
+use crate::game::{BagType, MinoShape, Point, TetrisError};
+use crate::options::game_option::GameOption;
+
+// 검증을 거쳐 GameOption을 만드는 빌더. 지정하지 않은 값은 GameOption::default를 따름
+// 예) 12x24 보드: GameOptionBuilder::new().column_count(12).row_count(24).block_size(30).build()
+// 만든 옵션으로 게임을 시작하는 전체 예시는 아래 테스트의 wide_board_example 참고
+#[derive(Default)]
+pub struct GameOptionBuilder {
+    option: GameOption,
+    block_size: Option<u32>, // 지정했다면 build에서 열/행 개수에 맞춰 캔버스 크기를 계산
+}
+
+impl GameOptionBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn board_width(mut self, board_width: u32) -> Self {
+        self.option.board_width = board_width;
+        self
+    }
+
+    pub fn board_height(mut self, board_height: u32) -> Self {
+        self.option.board_height = board_height;
+        self
+    }
+
+    pub fn column_count(mut self, column_count: u32) -> Self {
+        self.option.column_count = column_count;
+        self
+    }
+
+    pub fn row_count(mut self, row_count: u32) -> Self {
+        self.option.row_count = row_count;
+        self
+    }
+
+    // 블럭 한 칸 크기(px)로 캔버스 크기를 정함. 크기는 build에서 계산하므로 열/행 개수와 지정 순서는 상관없음
+    // 지정하면 board_width, board_height로 정한 값보다 우선함
+    pub fn block_size(mut self, block_size: u32) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
+    pub fn hidden_row_count(mut self, hidden_row_count: u32) -> Self {
+        self.option.hidden_row_count = hidden_row_count;
+        self
+    }
+
+    pub fn bag_mode(mut self, bag_mode: BagType) -> Self {
+        self.option.bag_mode = bag_mode;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.option.seed = Some(seed);
+        self
+    }
+
+    pub fn mino_list(mut self, mino_list: Vec<MinoShape>) -> Self {
+        self.option.mino_list = mino_list;
+        self
+    }
+
+    pub fn next_preview_count(mut self, next_preview_count: usize) -> Self {
+        self.option.next_preview_count = next_preview_count;
+        self
+    }
+
+    pub fn next_buffer_count(mut self, next_buffer_count: usize) -> Self {
+        self.option.next_buffer_count = next_buffer_count;
+        self
+    }
+
+    pub fn spawn_row(mut self, spawn_row: u32) -> Self {
+        self.option.spawn_row = spawn_row;
+        self
+    }
+
+    pub fn spawn_column_offset(mut self, spawn_column_offset: i32) -> Self {
+        self.option.spawn_column_offset = spawn_column_offset;
+        self
+    }
+
+    pub fn das(mut self, das: u32) -> Self {
+        self.option.das = das;
+        self
+    }
+
+    pub fn arr(mut self, arr: u32) -> Self {
+        self.option.arr = arr;
+        self
+    }
+
+    // 값을 검증하고 옵션 생성. 잘못된 값이 있다면 처음 발견한 필드를 알려줌
+    pub fn build(self) -> Result<GameOption, TetrisError> {
+        let mut option = self.option;
+
+        if option.column_count < 4 {
+            return Err(invalid_option("column_count", "must be at least 4"));
+        }
+
+        if option.row_count < 4 {
+            return Err(invalid_option("row_count", "must be at least 4"));
+        }
+
+        if option.row_count <= option.hidden_row_count {
+            return Err(invalid_option(
+                "row_count",
+                &format!(
+                    "must be greater than hidden_row_count {}",
+                    option.hidden_row_count
+                ),
+            ));
+        }
+
+        // 미노가 보이는 영역 위의 숨겨진 행에서 출현해야 함
+        if option.spawn_row >= option.hidden_row_count {
+            return Err(invalid_option(
+                "spawn_row",
+                &format!(
+                    "must be less than hidden_row_count {}",
+                    option.hidden_row_count
+                ),
+            ));
+        }
+
+        // 어떤 미노든 출현 위치에서 보드 좌우 밖으로 나가면 안 됨
+        let spawn_x = Point::start_point(option.column_count, 0, option.spawn_column_offset).x;
+        // 빈 목록이면 게임에서는 기본 7종을 쓰므로 그것으로 확인
+        let mino_list = if option.mino_list.is_empty() {
+            MinoShape::all()
+        } else {
+            option.mino_list.clone()
+        };
+        let out_of_board = mino_list
+            .iter()
+            .find(|mino| !mino.fits_columns(spawn_x, option.column_count));
+
+        if let Some(mino) = out_of_board {
+            return Err(invalid_option(
+                "spawn_column_offset",
+                &format!(
+                    "places {:?} outside column_count {}",
+                    mino.mino, option.column_count
+                ),
+            ));
+        }
+
+        if let Some(block_size) = self.block_size {
+            if block_size == 0 {
+                return Err(invalid_option("block_size", "must be positive"));
+            }
+
+            option.board_width = option.column_count * block_size;
+            option.board_height = option.row_count * block_size;
+        }
+
+        if option.board_width == 0 {
+            return Err(invalid_option("board_width", "must be positive"));
+        }
+
+        if option.board_height == 0 {
+            return Err(invalid_option("board_height", "must be positive"));
+        }
+
+        // 블럭이 캔버스에 딱 맞게 나누어 떨어져야 함
+        if !option.board_width.is_multiple_of(option.column_count) {
+            return Err(invalid_option(
+                "board_width",
+                &format!("must be a multiple of column_count {}", option.column_count),
+            ));
+        }
+
+        if !option.board_height.is_multiple_of(option.row_count) {
+            return Err(invalid_option(
+                "board_height",
+                &format!("must be a multiple of row_count {}", option.row_count),
+            ));
+        }
+
+        if option.das > MAX_INPUT_DELAY {
+            return Err(invalid_option(
+                "das",
+                &format!("must not exceed {}ms", MAX_INPUT_DELAY),
+            ));
+        }
+
+        if option.arr > MAX_INPUT_DELAY {
+            return Err(invalid_option(
+                "arr",
+                &format!("must not exceed {}ms", MAX_INPUT_DELAY),
+            ));
+        }
+
+        Ok(option)
+    }
+}
+
+fn invalid_option(field: &str, reason: &str) -> TetrisError {
+    TetrisError::InvalidOption(format!("{} {}", field, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameInfo;
+    use instant::Instant;
+
+    #[test]
+    fn default_options_are_valid() {
+        assert!(GameOptionBuilder::new().build().is_ok());
+    }
+
+    #[test]
+    fn block_size_does_not_depend_on_setter_order() {
+        let before = GameOptionBuilder::new()
+            .block_size(30)
+            .column_count(12)
+            .row_count(24)
+            .build()
+            .unwrap();
+        let after = GameOptionBuilder::new()
+            .column_count(12)
+            .row_count(24)
+            .block_size(30)
+            .build()
+            .unwrap();
+
+        for option in [before, after] {
+            assert_eq!((option.board_width, option.board_height), (360, 720));
+        }
+    }
+
+    #[test]
+    fn rows_must_exceed_hidden_rows() {
+        let result = GameOptionBuilder::new()
+            .row_count(4)
+            .hidden_row_count(4)
+            .spawn_row(0)
+            .build();
+
+        assert_eq!(
+            result.err(),
+            Some(invalid_option(
+                "row_count",
+                "must be greater than hidden_row_count 4"
+            ))
+        );
+    }
+
+    #[test]
+    fn reports_the_first_invalid_field() {
+        let error = |builder: GameOptionBuilder| match builder.build().err() {
+            Some(TetrisError::InvalidOption(message)) => message,
+            other => panic!("{:?}", other),
+        };
+
+        assert!(error(GameOptionBuilder::new().column_count(3)).starts_with("column_count"));
+        assert!(error(GameOptionBuilder::new().block_size(0)).starts_with("block_size"));
+        assert!(error(GameOptionBuilder::new().board_width(301)).starts_with("board_width"));
+        assert!(error(GameOptionBuilder::new().das(u32::MAX)).starts_with("das"));
+    }
+
+    // 12x24 넓은 보드 예시. 옵션을 만들고 검증한 뒤 그 옵션으로 게임을 시작함
+    #[test]
+    fn wide_board_example() {
+        let option = GameOptionBuilder::new()
+            .column_count(12)
+            .row_count(24)
+            .block_size(30)
+            .build()
+            .unwrap();
+
+        assert_eq!((option.column_count, option.row_count), (12, 24));
+        assert_eq!((option.board_width, option.board_height), (360, 720));
+
+        let mut game_info = GameInfo::with_option(option);
+        game_info.begin_play(Instant::now()).unwrap();
+        game_info.tick();
+
+        let tetris_board = &game_info.tetris_board;
+        assert_eq!(tetris_board.column_count, 12);
+        assert_eq!(tetris_board.row_count, 24 + tetris_board.hidden_row_count);
+        assert!(tetris_board.cells.iter().all(|row| row.len() == 12));
+
+        // 미노는 넓어진 보드의 가운데에서 출현
+        assert!(game_info.current_mino.is_some());
+        assert_eq!(game_info.current_position.x, 4);
+        assert!(!game_info.lose);
+    }
+}
@@ -0,0 +1,29 @@
+// 일정 간격마다 무작위 쓰레기 줄이 올라오는 1인용 생존 챌린지 설정
+// 같은 시드와 간격이면 항상 같은 쓰레기 패턴이 나옴
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GarbageChallenge {
+    pub seed: u64,             // 쓰레기 줄 생성용 난수 시드
+    pub garbage_interval: u64, // 처음 쓰레기 줄이 올라오는 간격 (밀리초)
+    pub min_interval: u64,     // 난이도가 올라가도 이보다 짧아지지는 않음 (밀리초)
+    pub ramp_factor: f64,      // 한번 올라올 때마다 간격에 곱할 배율 (1보다 작으면 점점 빨라짐)
+    pub max_lines: u32,        // 한번에 올라오는 최대 줄 수 (1 ~ max_lines 사이에서 무작위)
+}
+
+impl GarbageChallenge {
+    // 다음 쓰레기 줄까지의 간격
+    pub fn next_interval(&self, current_interval: u64) -> u64 {
+        ((current_interval as f64 * self.ramp_factor).round() as u64).max(self.min_interval)
+    }
+}
+
+impl Default for GarbageChallenge {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            garbage_interval: 10000,
+            min_interval: 2000,
+            ramp_factor: 0.9,
+            max_lines: 2,
+        }
+    }
+}
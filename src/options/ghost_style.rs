@@ -0,0 +1,10 @@
+// 고스트(하드드랍 위치 미리보기) 렌더링 방식
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GhostStyle {
+    #[default]
+    SolidColor, // 고스트 전용 단색으로 그림 (기존 방식)
+    TransparentPieceColor {
+        alpha: f64,
+    }, // 현재 미노 색상을 alpha(0.0 ~ 1.0) 투명도로 덧그림
+    Hidden, // 고스트를 그리지 않음
+}
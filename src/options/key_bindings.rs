@@ -0,0 +1,109 @@
+use crate::game::Event;
+
+// 키보드 키코드와 게임 동작의 연결. 게임 도중에도 필드를 바꿔서 다시 지정할 수 있음
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBindings {
+    pub left_move: u32,
+    pub right_move: u32,
+    pub soft_drop: u32,
+    pub hard_drop: u32,
+    pub left_rotate: u32,
+    pub right_rotate: u32,
+    pub right_rotate_alt: u32, // 오른쪽 회전 보조키 (방향키 위)
+    pub double_rotate: u32,
+    pub hold: u32,
+    pub hold_alt: u32, // 홀드 보조키
+    pub help: u32,     // 조작법 도움말 표시/숨김
+    pub pause: u32,    // 일시정지/재개
+}
+
+impl KeyBindings {
+    // 키를 눌렀을 때의 게임 이벤트
+    pub fn press_event(&self, key_code: u32) -> Option<Event> {
+        match key_code {
+            code if code == self.left_move => Some(Event::LeftMove),
+            code if code == self.right_move => Some(Event::RightMove),
+            code if code == self.soft_drop => Some(Event::SoftDrop),
+            code if code == self.hard_drop => Some(Event::HardDrop),
+            code if code == self.left_rotate => Some(Event::LeftRotate),
+            code if code == self.right_rotate || code == self.right_rotate_alt => {
+                Some(Event::RightRotate)
+            }
+            code if code == self.double_rotate => Some(Event::DoubleRotate),
+            code if code == self.hold || code == self.hold_alt => Some(Event::Hold),
+            _ => None,
+        }
+    }
+
+    // 키를 뗐을 때의 게임 이벤트 (좌우 이동키, 소프트드랍 키만 해당)
+    pub fn release_event(&self, key_code: u32) -> Option<Event> {
+        match key_code {
+            code if code == self.left_move => Some(Event::LeftRelease),
+            code if code == self.right_move => Some(Event::RightRelease),
+            code if code == self.soft_drop => Some(Event::SoftDropRelease),
+            _ => None,
+        }
+    }
+
+    // 도움말에 보여줄 (동작 이름, 키 이름) 목록
+    pub fn help_lines(&self) -> Vec<String> {
+        [
+            ("Move Left", self.left_move),
+            ("Move Right", self.right_move),
+            ("Soft Drop", self.soft_drop),
+            ("Hard Drop", self.hard_drop),
+            ("Rotate Left", self.left_rotate),
+            ("Rotate Right", self.right_rotate),
+            ("Rotate Right", self.right_rotate_alt),
+            ("Rotate 180", self.double_rotate),
+            ("Hold", self.hold),
+            ("Hold", self.hold_alt),
+            ("Help", self.help),
+            ("Pause", self.pause),
+        ]
+        .iter()
+        .map(|(action, key_code)| format!("{:<13}{}", action, key_name(*key_code)))
+        .collect()
+    }
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            left_move: 37,        // ←
+            right_move: 39,       // →
+            soft_drop: 40,        // ↓
+            hard_drop: 32,        // spacebar
+            left_rotate: 90,      // z
+            right_rotate: 88,     // x
+            right_rotate_alt: 38, // ↑
+            double_rotate: 65,    // a
+            hold: 16,             // shift
+            hold_alt: 67,         // c
+            help: 72,             // h
+            pause: 80,            // p
+        }
+    }
+}
+
+// 키코드를 사람이 읽을 수 있는 이름으로 변환
+pub fn key_name(key_code: u32) -> String {
+    match key_code {
+        8 => "Backspace".into(),
+        9 => "Tab".into(),
+        13 => "Enter".into(),
+        16 => "Shift".into(),
+        17 => "Ctrl".into(),
+        18 => "Alt".into(),
+        27 => "Esc".into(),
+        32 => "Space".into(),
+        37 => "←".into(),
+        38 => "↑".into(),
+        39 => "→".into(),
+        40 => "↓".into(),
+        48..=57 | 65..=90 => char::from_u32(key_code)
+            .map(String::from)
+            .unwrap_or_default(),
+        _ => format!("Key {}", key_code),
+    }
+}
@@ -0,0 +1,13 @@
+// 바닥에 닿은 미노의 고정 타이머를 언제 다시 셀지 정하는 규칙
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockDelayPolicy {
+    StepReset,                   // 아래로 내려갔을 때만 타이머 초기화
+    MoveReset { max_reset: u8 }, // 이동/회전 성공 시마다 초기화. 미노당 max_reset번까지
+    Infinite,                    // 제한없이 초기화하고 중력으로는 고정하지 않음 (연습용)
+}
+
+impl Default for LockDelayPolicy {
+    fn default() -> Self {
+        Self::MoveReset { max_reset: 8 }
+    }
+}
@@ -1 +1,12 @@
+pub mod adaptive_speed;
+pub mod block_style;
+pub mod cell_palette;
+pub mod cheese_race;
+pub mod fading_blocks;
 pub mod game_option;
+pub mod game_option_builder;
+pub mod garbage_challenge;
+pub mod ghost_style;
+pub mod key_bindings;
+pub mod lock_delay_policy;
+pub mod theme;
@@ -0,0 +1,53 @@
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::constants::color::{
+    BOARD_STROKE_DEFAULT_COLOR, HOLD_STROKE_DEFAULT_COLOR, NEXT_STROKE_DEFAULT_COLOR,
+};
+use crate::options::cell_palette::CellPalette;
+
+// 패널(보드, 넥스트, 홀드) 테두리 설정
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PanelBorder {
+    pub color: String, // 테두리 색상
+    pub width: f64,    // 테두리 두께 (px)
+}
+
+impl PanelBorder {
+    pub fn new(color: &str, width: f64) -> Self {
+        Self {
+            color: color.into(),
+            width,
+        }
+    }
+}
+
+// 화면 테마. 호스트 페이지 디자인에 맞춰 패널별로 바꿀 수 있음
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub board_border: PanelBorder,
+    pub next_border: PanelBorder,
+    pub hold_border: PanelBorder,
+    pub cell_palette: CellPalette, // 블럭 색상
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            board_border: PanelBorder::new(BOARD_STROKE_DEFAULT_COLOR, 1.0),
+            next_border: PanelBorder::new(NEXT_STROKE_DEFAULT_COLOR, 1.0),
+            hold_border: PanelBorder::new(HOLD_STROKE_DEFAULT_COLOR, 1.0),
+            cell_palette: Default::default(),
+        }
+    }
+}
+
+impl Theme {
+    // 기본 테마에서 블럭 색상만 고대비 색상으로 바꾼 테마
+    pub fn high_contrast() -> Self {
+        Self {
+            cell_palette: CellPalette::high_contrast(),
+            ..Default::default()
+        }
+    }
+}
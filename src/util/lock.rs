@@ -0,0 +1,38 @@
+use std::sync::{Mutex, MutexGuard};
+
+// 잠금을 얻음. 다른 곳에서 잠금을 잡은 채로 패닉이 나서 오염(poison)된 경우에도 경고만 남기고 그대로 이어서 씀
+// 오염 표시도 지워서 다음 잠금부터는 평소처럼 동작하게 함
+pub fn lock_or_recover<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|error| {
+        log::warn!("recovered a poisoned lock");
+        mutex.clear_poison();
+        error.into_inner()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn recovers_a_poisoned_lock() {
+        let mutex = Arc::new(Mutex::new(vec![1, 2]));
+
+        // 잠금을 잡은 채로 패닉을 내서 오염시킴
+        let poisoner = Arc::clone(&mutex);
+        let result = std::thread::spawn(move || {
+            let mut values = poisoner.lock().unwrap();
+            values.push(3);
+            panic!("panic while holding the lock");
+        })
+        .join();
+        assert!(result.is_err());
+        assert!(mutex.is_poisoned());
+
+        // 패닉 직전까지의 상태로 이어서 쓰고, 오염 표시도 지워짐
+        lock_or_recover(&mutex).push(4);
+        assert!(!mutex.is_poisoned());
+        assert_eq!(*mutex.lock().unwrap(), vec![1, 2, 3, 4]);
+    }
+}
@@ -1,5 +1,17 @@
+pub mod lock;
+pub use lock::*;
+
+pub mod number;
+pub use number::*;
+
 pub mod random;
 pub use random::*;
 
+pub mod rng;
+pub use rng::*;
+
 pub mod rotate;
 pub use rotate::*;
+
+pub mod time;
+pub use time::*;
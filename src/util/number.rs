@@ -0,0 +1,14 @@
+// 세 자리마다 쉼표를 넣어 변환 (ex: 1234567 -> 1,234,567)
+pub fn format_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut text = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            text.push(',');
+        }
+        text.push(digit);
+    }
+
+    text
+}
@@ -1,18 +1,38 @@
-use rand::seq::SliceRandom;
+use super::rng::Rng;
 
-pub fn random_select<T: Clone>(array: &[T]) -> T {
-    let temp = array.to_vec();
+pub fn random_select<T: Clone>(rng: &mut dyn Rng, array: &[T]) -> T {
+    let index = rng.next_u32() as usize % array.len();
 
-    let mut rng = rand::thread_rng();
+    array[index].clone()
+}
+
+// 기록에 있는 값이 나오면 정해진 횟수까지 다시 뽑음. 끝까지 겹치면 마지막으로 뽑은 값을 그대로 씀
+pub fn select_with_history<T: Clone>(
+    rng: &mut dyn Rng,
+    array: &[T],
+    roll_count: u32,
+    in_history: impl Fn(&T) -> bool,
+) -> T {
+    let mut selected = random_select(rng, array);
+
+    for _ in 1..roll_count {
+        if !in_history(&selected) {
+            break;
+        }
+        selected = random_select(rng, array);
+    }
 
-    temp.choose_multiple(&mut rng, 1).next().unwrap().to_owned()
+    selected
 }
 
-pub fn shuffle<T: Clone>(array: &[T]) -> impl Iterator<Item = T> {
+pub fn shuffle<T: Clone>(rng: &mut dyn Rng, array: &[T]) -> impl Iterator<Item = T> {
     let mut temp = array.to_vec();
 
-    let mut rng = rand::thread_rng();
-    temp.shuffle(&mut rng);
+    // Fisher-Yates
+    for i in (1..temp.len()).rev() {
+        let j = rng.next_u32() as usize % (i + 1);
+        temp.swap(i, j);
+    }
 
     temp.into_iter()
 }
@@ -0,0 +1,117 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+// 게임 로직에서 사용하는 난수 생성기
+// 테스트용 고정 난수나 플랫폼별 난수 소스로 교체할 수 있도록 최소한의 기능만 요구함
+pub trait Rng: std::fmt::Debug + Send {
+    fn next_u32(&mut self) -> u32;
+
+    // 같은 수열을 이어서 만들 수 있는 내부 상태. 내보낼 수 없는 생성기는 None
+    fn export_state(&self) -> Option<u64> {
+        None
+    }
+}
+
+// 기본 난수 생성기 (시스템 엔트로피로 초기화)
+#[derive(Debug)]
+pub struct DefaultRng(StdRng);
+
+impl DefaultRng {
+    pub fn new() -> Self {
+        Self(StdRng::from_entropy())
+    }
+}
+
+impl Default for DefaultRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Rng for DefaultRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+}
+
+// 시드를 따로 정하지 않았을 때 사용할 임의의 64비트 시드
+pub fn random_seed() -> u64 {
+    let mut seed_rng = DefaultRng::new();
+    ((seed_rng.next_u32() as u64) << 32) | seed_rng.next_u32() as u64
+}
+
+// 시드로 초기화하는 xorshift64* 난수 생성기
+// 같은 시드라면 플랫폼과 관계없이 같은 수열을 만들기 때문에 리플레이 재현에 사용
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct XorShiftRng {
+    state: u64,
+}
+
+impl XorShiftRng {
+    pub fn new(seed: u64) -> Self {
+        // 상태가 0이면 계속 0만 나오므로 임의의 상수로 대체
+        let state = if seed == 0 {
+            0x9E37_79B9_7F4A_7C15
+        } else {
+            seed
+        };
+
+        Self { state }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+}
+
+impl Rng for XorShiftRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    // 시드가 곧 초기 상태이므로 XorShiftRng::new(state)로 그대로 복원됨
+    fn export_state(&self) -> Option<u64> {
+        Some(self.state)
+    }
+}
+
+// 날짜 문자열(YYYY-MM-DD)로 오늘의 챌린지 시드를 만듦
+pub fn daily_seed(date: &str) -> u64 {
+    fnv1a_hash(date.trim().bytes())
+}
+
+// 플랫폼이나 버전에 따라 바뀌면 안되는 값에 사용하는 FNV-1a 64비트 해시 (std Hasher는 고정된 결과를 보장하지 않음)
+pub fn fnv1a_hash(bytes: impl IntoIterator<Item = u8>) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+    bytes.into_iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_date_gives_the_same_seed() {
+        assert_eq!(daily_seed("2024-03-01"), daily_seed("2024-03-01"));
+        assert_eq!(daily_seed(" 2024-03-01\n"), daily_seed("2024-03-01"));
+        assert_ne!(daily_seed("2024-03-01"), daily_seed("2024-03-02"));
+    }
+
+    #[test]
+    fn fnv1a_hash_matches_the_reference_values() {
+        // 버전이 바뀌어도 같은 날짜는 같은 시드여야 하므로 고정된 값과 비교
+        assert_eq!(fnv1a_hash([]), 0xCBF2_9CE4_8422_2325);
+        assert_eq!(fnv1a_hash(*b"a"), 0xAF63_DC4C_8601_EC8C);
+        assert_eq!(fnv1a_hash(*b"foobar"), 0x8594_4171_F739_67E8);
+    }
+}
@@ -71,3 +71,10 @@ pub const KICK_INDEX_I: [[[i64; 2];4];8] = [
     [[-2, 0], [1, 0], [-2, -1], [1, 2]], //32
 ];
 
+//180 TABLE (SRS+): IF 180 ROTATION FAILS, TRY THESE. INDEXED BY CURRENT ROTATION STATE
+pub const KICK_INDEX_180: [[[i64; 2];5];4] = [
+    [[0, 1], [1, 1], [-1, 1], [1, 0], [-1, 0]],   //02
+    [[1, 0], [1, 2], [1, 1], [0, 2], [0, 1]],     //13
+    [[0, -1], [-1, -1], [1, -1], [-1, 0], [1, 0]], //20
+    [[-1, 0], [-1, 2], [-1, 1], [0, 2], [0, 1]],  //31
+];
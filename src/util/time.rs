@@ -0,0 +1,13 @@
+use std::time::Duration;
+
+// 플레이 시간을 분:초.밀리초 형태로 변환 (ex: 01:23.456)
+pub fn format_play_time(time: Duration) -> String {
+    let millis = time.as_millis();
+
+    format!(
+        "{:02}:{:02}.{:03}",
+        millis / 60_000,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
@@ -0,0 +1,91 @@
+use std::cell::RefCell;
+
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+use crate::js_bind::document::document;
+
+// 게임 보드를 먼저 그려두는 화면 밖 캔버스 (double-buffer 기능)
+// OffscreenCanvas 대신 문서에 붙이지 않은 캔버스 요소를 쓰므로 OffscreenCanvas가 없는 브라우저에서도 동작함
+// 캔버스를 만들 수 없는 환경이라면 None을 반환하고, 호출하는 쪽은 화면 캔버스에 바로 그림
+pub struct BackBuffer {
+    pub canvas: HtmlCanvasElement,
+    pub context: CanvasRenderingContext2d,
+}
+
+thread_local! {
+    static BACK_BUFFER: RefCell<Option<BackBuffer>> = const { RefCell::new(None) };
+}
+
+impl BackBuffer {
+    // 화면 캔버스와 같은 실제 픽셀 크기와 변환을 가진 버퍼를 가져옴
+    // 새로 만들었거나 크기가 바뀌어 내용이 지워졌다면 두번째 값이 true
+    pub fn for_canvas(front: &CanvasRenderingContext2d) -> Option<(Self, bool)> {
+        let front_canvas = front.canvas()?;
+
+        BACK_BUFFER.with(|back_buffer| {
+            let mut back_buffer = back_buffer.borrow_mut();
+            let mut cleared = false;
+
+            if back_buffer.is_none() {
+                *back_buffer = Self::create().ok();
+                cleared = true;
+            }
+
+            let buffer = back_buffer.as_ref()?;
+
+            if buffer.canvas.width() != front_canvas.width()
+                || buffer.canvas.height() != front_canvas.height()
+            {
+                buffer.canvas.set_width(front_canvas.width());
+                buffer.canvas.set_height(front_canvas.height());
+                cleared = true;
+            }
+
+            // 화면 캔버스의 devicePixelRatio 변환을 그대로 따라서 css 크기 기준 좌표로 그림
+            let transform = front.get_transform().ok()?;
+            buffer
+                .context
+                .set_transform(
+                    transform.a(),
+                    transform.b(),
+                    transform.c(),
+                    transform.d(),
+                    transform.e(),
+                    transform.f(),
+                )
+                .ok()?;
+
+            Some((
+                Self {
+                    canvas: buffer.canvas.clone(),
+                    context: buffer.context.clone(),
+                },
+                cleared,
+            ))
+        })
+    }
+
+    fn create() -> Result<Self, JsValue> {
+        let canvas = document()
+            .create_element("canvas")?
+            .dyn_into::<HtmlCanvasElement>()?;
+
+        let context = canvas
+            .get_context("2d")?
+            .ok_or_else(|| JsValue::from_str("2d context is not supported"))?
+            .dyn_into::<CanvasRenderingContext2d>()?;
+
+        Ok(Self { canvas, context })
+    }
+
+    // 버퍼 내용을 화면 캔버스에 한번에 복사
+    pub fn present(&self, front: &CanvasRenderingContext2d) -> Result<(), JsValue> {
+        front.save();
+        front.set_transform(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)?;
+        let result = front.draw_image_with_html_canvas_element(&self.canvas, 0.0, 0.0);
+        front.restore();
+
+        result
+    }
+}
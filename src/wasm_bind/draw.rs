@@ -1,7 +1,14 @@
-use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
+use wasm_bindgen::prelude::wasm_bindgen;
 use web_sys::CanvasRenderingContext2d;
 
+use crate::constants::color::GLYPH_COLOR;
+use crate::options::block_style::BlockStyle;
+
+// 블럭 글자의 최소 크기 (px). 이보다 작은 블럭에는 글자를 그리지 않음
+const MIN_GLYPH_FONT_SIZE: f64 = 8.0;
+
 #[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
 pub fn draw_block(
     context: CanvasRenderingContext2d,
     x: f64,
@@ -9,21 +16,115 @@ pub fn draw_block(
     block_width_size: f64,
     block_height_size: f64,
     color: &str,
+    glyph: Option<String>,
+    style: BlockStyle,
 ) {
     let stroke_size = 0.5;
 
-    context.set_stroke_style(&JsValue::from_str("#000000")); // 테두리 색상
-    context.set_fill_style(&JsValue::from_str(color)); // 내부 색상
+    context.set_fill_style_str(color); // 내부 색상
     context.fill_rect(
         x,
         y,
         block_width_size - stroke_size,
         block_height_size - stroke_size,
     );
-    context.stroke_rect(
-        x,
-        y,
-        block_width_size - stroke_size,
-        block_height_size - stroke_size,
-    );
+
+    if let Some(stroke_width) = style.stroke() {
+        context.set_stroke_style_str("#000000"); // 테두리 색상
+        context.set_line_width(stroke_width);
+        context.stroke_rect(
+            x,
+            y,
+            block_width_size - stroke_size,
+            block_height_size - stroke_size,
+        );
+    }
+
+    if let (true, Some(glyph)) = (style.draw_glyph, glyph) {
+        draw_glyph(
+            &context,
+            x,
+            y,
+            block_width_size - stroke_size,
+            block_height_size - stroke_size,
+            &glyph,
+        );
+    }
+}
+
+// 여러 블럭을 한번에 그릴 때 블럭 하나의 위치와 색상
+pub struct BlockDraw<'a> {
+    pub x: f64,
+    pub y: f64,
+    pub color: &'a str,
+    pub glyph: Option<String>,
+}
+
+// draw_block과 같은 모양으로 여러 블럭을 그리되, 색상별로 모아서 채우기 스타일은 색상당 한번만 지정
+// 테두리도 한 경로로 모아서 한번에 그림. 보드 전체를 다시 그릴 때 사용
+pub fn draw_blocks_batched(
+    context: &CanvasRenderingContext2d,
+    blocks: &[BlockDraw],
+    block_width_size: f64,
+    block_height_size: f64,
+    style: BlockStyle,
+) {
+    let stroke_size = 0.5;
+    let width = block_width_size - stroke_size;
+    let height = block_height_size - stroke_size;
+
+    // 처음 나온 순서대로 색상을 모음
+    let mut colors: Vec<&str> = vec![];
+    for block in blocks {
+        if !colors.contains(&block.color) {
+            colors.push(block.color);
+        }
+    }
+
+    for color in colors {
+        context.set_fill_style_str(color);
+
+        for block in blocks.iter().filter(|block| block.color == color) {
+            context.fill_rect(block.x, block.y, width, height);
+        }
+    }
+
+    if let Some(stroke_width) = style.stroke() {
+        context.begin_path();
+        for block in blocks {
+            context.rect(block.x, block.y, width, height);
+        }
+        context.set_stroke_style_str("#000000");
+        context.set_line_width(stroke_width);
+        context.stroke();
+    }
+
+    if style.draw_glyph {
+        for block in blocks {
+            if let Some(glyph) = &block.glyph {
+                draw_glyph(context, block.x, block.y, width, height, glyph);
+            }
+        }
+    }
+}
+
+// 블럭 가운데에 글자를 그림. 글자는 블럭 크기에 맞춰 키우고, 너무 작아서 알아볼 수 없다면 그리지 않음
+fn draw_glyph(
+    context: &CanvasRenderingContext2d,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    glyph: &str,
+) {
+    let font_size = (width.min(height) * 0.6).floor();
+
+    if font_size >= MIN_GLYPH_FONT_SIZE {
+        context.set_fill_style_str(GLYPH_COLOR);
+        context.set_font(&format!("bold {}px monospace", font_size));
+        context.set_text_align("center");
+        context.set_text_baseline("middle");
+        let _ = context.fill_text(glyph, x + width / 2.0, y + height / 2.0);
+        context.set_text_align("start");
+    }
 }
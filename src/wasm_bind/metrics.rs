@@ -0,0 +1,129 @@
+use crate::constants::board::{HOLD_PANEL_ROW_COUNT, PANEL_BLOCK_SIZE, PANEL_COLUMN_COUNT};
+
+// 넥스트 패널의 행 개수. 미노마다 위쪽 빈 줄 1칸 + 4칸을 쓰고 맨 아래에 빈 줄 1칸을 둠
+pub fn next_panel_row_count(preview_count: usize) -> u32 {
+    preview_count as u32 * 5 + 1
+}
+
+// 넥스트 패널 캔버스 크기 (가로, 세로 px)
+pub fn next_panel_size(preview_count: usize) -> (u32, u32) {
+    (
+        PANEL_COLUMN_COUNT * PANEL_BLOCK_SIZE,
+        next_panel_row_count(preview_count) * PANEL_BLOCK_SIZE,
+    )
+}
+
+// 홀드 패널 캔버스 크기 (가로, 세로 px)
+pub fn hold_panel_size() -> (u32, u32) {
+    (
+        PANEL_COLUMN_COUNT * PANEL_BLOCK_SIZE,
+        HOLD_PANEL_ROW_COUNT * PANEL_BLOCK_SIZE,
+    )
+}
+
+// 캔버스 크기에서 파생되는 값들을 한곳에 모아둔 구조체
+// 캔버스 크기가 바뀌면 새로 만들기만 하면 블럭 크기와 위치 계산이 모두 따라서 갱신됨
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridMetrics {
+    pub width: f64,        // 캔버스 가로 길이 (px)
+    pub height: f64,       // 캔버스 세로 길이 (px)
+    pub block_width: f64,  // 블럭 하나의 가로 길이 (px)
+    pub block_height: f64, // 블럭 하나의 세로 길이 (px)
+    pub offset_x: f64,     // 격자를 가운데 정렬하기 위한 왼쪽 여백 (px)
+    pub offset_y: f64,     // 격자를 가운데 정렬하기 위한 위쪽 여백 (px)
+}
+
+impl GridMetrics {
+    // 캔버스 비율이 열/행 비율과 달라도 블럭이 찌그러지지 않도록 정사각형 블럭으로 맞추고 남는 공간은 양쪽에 나눔
+    // 블럭 크기는 정수로 자르지 않으므로 나누어 떨어지지 않는 크기에서도 격자가 캔버스 끝까지 채워짐
+    pub fn new(width: u32, height: u32, column_count: u32, row_count: u32) -> Self {
+        let width = width as f64;
+        let height = height as f64;
+        let column_count = column_count.max(1) as f64;
+        let row_count = row_count.max(1) as f64;
+        let block_size = (width / column_count).min(height / row_count);
+
+        Self {
+            width,
+            height,
+            block_width: block_size,
+            block_height: block_size,
+            offset_x: (width - block_size * column_count) / 2.0,
+            offset_y: (height - block_size * row_count) / 2.0,
+        }
+    }
+
+    // 격자 좌표(열, 행)의 픽셀 위치. 애니메이션 오프셋 등으로 조금 벗어난 위치도 받을 수 있도록 실수로 받음
+    // 블럭 경계가 픽셀 사이에 걸쳐 흐려지지 않도록 반올림
+    pub fn block_position(&self, x: f64, y: f64) -> (f64, f64) {
+        self.clamp_position(
+            (self.offset_x + x * self.block_width).round(),
+            (self.offset_y + y * self.block_height).round(),
+        )
+    }
+
+    // 블럭이 캔버스 밖으로 나가지 않도록 픽셀 위치를 보정
+    pub fn clamp_position(&self, x: f64, y: f64) -> (f64, f64) {
+        let max_x = (self.width - self.block_width).max(0.0);
+        let max_y = (self.height - self.block_height).max(0.0);
+
+        (x.clamp(0.0, max_x), y.clamp(0.0, max_y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square_board_gives_square_blocks() {
+        let metrics = GridMetrics::new(400, 400, 10, 10);
+
+        assert_eq!(metrics.block_width, 40.0);
+        assert_eq!(metrics.block_height, 40.0);
+        assert_eq!((metrics.offset_x, metrics.offset_y), (0.0, 0.0));
+        assert_eq!(metrics.block_position(9.0, 9.0), (360.0, 360.0));
+    }
+
+    #[test]
+    fn wide_board_keeps_blocks_square() {
+        // 10x20 격자를 가로로 긴 캔버스에 그리면 블럭은 세로 기준 크기로 맞추고 좌우에 여백을 둠
+        let metrics = GridMetrics::new(400, 400, 10, 20);
+
+        assert_eq!(metrics.block_width, metrics.block_height);
+        assert_eq!(metrics.block_width, 20.0);
+        assert_eq!(metrics.offset_x, 100.0);
+    }
+
+    #[test]
+    fn positions_stay_inside_the_canvas_after_a_resize() {
+        let before = GridMetrics::new(300, 600, 10, 20);
+        let after = GridMetrics::new(150, 300, 10, 20);
+        assert_eq!(after.block_width, before.block_width / 2.0);
+
+        // 떨어지는 중간이나 흔들림 오프셋으로 격자를 조금 벗어난 위치
+        let positions = [(-0.4, -0.7), (9.6, 19.5), (4.5, 10.25), (12.0, 25.0)];
+
+        for (x, y) in positions {
+            let (pixel_x, pixel_y) = after.block_position(x, y);
+
+            assert!(pixel_x >= 0.0 && pixel_x + after.block_width <= after.width);
+            assert!(pixel_y >= 0.0 && pixel_y + after.block_height <= after.height);
+        }
+    }
+
+    #[test]
+    fn last_column_reaches_the_edge_of_a_305px_board() {
+        let metrics = GridMetrics::new(305, 610, 10, 20);
+
+        assert_eq!(metrics.block_width, 30.5);
+
+        let (last_x, _) = metrics.block_position(9.0, 0.0);
+        assert_eq!(last_x + metrics.block_width, metrics.width);
+
+        // 정수 나눗셈(30px)이었다면 오른쪽에 5px 틈이 남음
+        let (last_x, last_y) = metrics.block_position(9.0, 19.0);
+        assert!(metrics.width - (last_x + metrics.block_width) < 1.0);
+        assert!(metrics.height - (last_y + metrics.block_height) < 1.0);
+    }
+}
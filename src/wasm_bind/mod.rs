@@ -1,5 +1,19 @@
+// 캔버스 크기에서 파생되는 값(metrics)은 DOM 없이 계산할 수 있으므로 어느 타겟에서나 컴파일
+// 캔버스에 직접 그리는 나머지는 wasm32 전용
+#[cfg(all(target_arch = "wasm32", feature = "double-buffer"))]
+pub mod back_buffer;
+#[cfg(target_arch = "wasm32")]
 pub mod draw;
+pub mod metrics;
+#[cfg(target_arch = "wasm32")]
+pub mod pixel_ratio;
+#[cfg(target_arch = "wasm32")]
 pub mod render;
 
+#[cfg(target_arch = "wasm32")]
 pub use draw::*;
+pub use metrics::*;
+#[cfg(target_arch = "wasm32")]
+pub use pixel_ratio::*;
+#[cfg(target_arch = "wasm32")]
 pub use render::*;
@@ -0,0 +1,53 @@
+use wasm_bindgen::JsValue;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
+
+use crate::js_bind::window::window;
+
+// 마지막으로 맞춰둔 크기를 "css 가로 css 세로 실제 가로 실제 세로" 형태로 캔버스에 기록해두는 속성
+const SCALE_ATTRIBUTE: &str = "data-scaled-size";
+
+// 고해상도 화면에서 흐려지지 않도록 캔버스 실제 픽셀 크기를 devicePixelRatio배로 늘리고 컨텍스트도 같은 비율로 확대
+// 화면에 보이는 크기는 그대로 두고, 그리는 쪽은 계속 css 크기 기준 좌표를 쓰면 됨. css 크기(가로, 세로)를 반환
+pub fn scale_to_device_pixel_ratio(
+    canvas: &HtmlCanvasElement,
+    context: &CanvasRenderingContext2d,
+) -> Result<(u32, u32), JsValue> {
+    let ratio = window().device_pixel_ratio().max(1.0);
+
+    // 캔버스 크기가 마지막으로 맞춰둔 실제 크기와 다르다면 마크업에서 새로 지정한 css 크기로 봄
+    let scaled_size = canvas
+        .get_attribute(SCALE_ATTRIBUTE)
+        .map(|text| {
+            text.split(' ')
+                .filter_map(|value| value.parse::<u32>().ok())
+                .collect::<Vec<_>>()
+        })
+        .filter(|size| size.len() == 4 && size[2] == canvas.width() && size[3] == canvas.height());
+
+    let (css_width, css_height) = match scaled_size {
+        Some(size) => (size[0], size[1]),
+        None => (canvas.width(), canvas.height()),
+    };
+
+    let width = (css_width as f64 * ratio).round() as u32;
+    let height = (css_height as f64 * ratio).round() as u32;
+
+    if canvas.width() != width || canvas.height() != height {
+        canvas.set_width(width);
+        canvas.set_height(height);
+        canvas.set_attribute(
+            "style",
+            &format!("width: {}px; height: {}px;", css_width, css_height),
+        )?;
+    }
+
+    canvas.set_attribute(
+        SCALE_ATTRIBUTE,
+        &format!("{} {} {} {}", css_width, css_height, width, height),
+    )?;
+
+    // 크기를 바꾸면 변환이 초기화되므로 매번 다시 지정
+    context.set_transform(ratio, 0.0, 0.0, ratio, 0.0, 0.0)?;
+
+    Ok((css_width, css_height))
+}
@@ -2,24 +2,53 @@ use wasm_bindgen::prelude::Closure;
 use wasm_bindgen::{prelude::wasm_bindgen, JsValue};
 
 use std::cell::RefCell;
-use std::collections::VecDeque;
 use std::f64;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 use wasm_bindgen::JsCast;
+use web_sys::{CanvasRenderingContext2d, HtmlCanvasElement};
 
 use crate::constants::color::{
-    BOARD_DEFAULT_COLOR, BOARD_STROKE_DEFAULT_COLOR, HOLD_DEFAULT_COLOR, HOLD_STROKE_DEFAULT_COLOR,
-    NEXT_DEFAULT_COLOR, NEXT_STROKE_DEFAULT_COLOR,
+    BOARD_DEFAULT_COLOR, BOARD_GRID_DEFAULT_COLOR, HOLD_DEFAULT_COLOR, NEXT_DEFAULT_COLOR,
 };
+use crate::constants::dom::{GAME_CANVAS_ID, HOLD_CANVAS_ID, NEXT_CANVAS_ID};
 use crate::game::tetris_board::TetrisBoard;
 use crate::game::tetris_cell::TetrisCell;
-use crate::game::MinoShape;
-use crate::js_bind::body::body;
+use crate::game::{GameInfo, MinoShape};
+use crate::js_bind::document::document;
 use crate::js_bind::request_animation_frame::request_animation_frame;
+use crate::js_bind::window::window;
+use crate::options::block_style::BlockStyle;
+use crate::options::cell_palette::CellPalette;
+use crate::options::theme::PanelBorder;
+use crate::util::lock_or_recover;
+
+#[cfg(feature = "double-buffer")]
+use super::back_buffer::BackBuffer;
+use super::draw::{draw_block, draw_blocks_batched, BlockDraw};
+use super::metrics::GridMetrics;
+use super::pixel_ratio::scale_to_device_pixel_ratio;
+
+// id로 캔버스를 찾아 devicePixelRatio에 맞춘 2d 컨텍스트와 css 크기(가로, 세로)를 반환
+// DOM이 아직 준비되지 않았다면 패닉 대신 에러 반환
+fn canvas_context(id: &str) -> Result<(CanvasRenderingContext2d, u32, u32), JsValue> {
+    let canvas = document()
+        .get_element_by_id(id)
+        .ok_or_else(|| JsValue::from_str(&format!("{} is not found", id)))?
+        .dyn_into::<HtmlCanvasElement>()?;
 
-use super::draw::draw_block;
+    let context = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("2d context is not supported"))?
+        .dyn_into::<CanvasRenderingContext2d>()?;
+
+    let (width, height) = scale_to_device_pixel_ratio(&canvas, &context)?;
+
+    Ok((context, width, height))
+}
 
 #[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
 pub fn render_board(
     board_unfolded: Vec<i32>,
     board_width: u32,
@@ -27,41 +56,238 @@ pub fn render_board(
     column_count: u32,
     row_count: u32,
     hidden_row_count: u32,
-) {
+    block_style: BlockStyle,
+    border: &PanelBorder,
+    show_grid: bool,
+    palette: &CellPalette,
+    ghost: Option<GhostDraw>,
+) -> Result<(), JsValue> {
     let visible_row_count = row_count - hidden_row_count;
 
-    let block_width_size = (board_width / column_count as u32) as f64;
-    let block_height_size = (board_height / visible_row_count as u32) as f64;
-
     let tetris_board = TetrisBoard::from_unfold(
-        board_unfolded,
+        board_unfolded.clone(),
         board_width,
         board_height,
         column_count,
         row_count,
         hidden_row_count,
-    );
+    )
+    .map_err(|error| JsValue::from_str(&error.to_string()))?;
+
+    let (front, width, height) = canvas_context(GAME_CANVAS_ID)?;
+
+    // double-buffer 기능이 켜져있다면 화면 밖 캔버스에 그린 뒤 한번에 복사
+    // 버퍼를 만들 수 없는 환경이라면 기능이 꺼져있을 때처럼 화면 캔버스에 바로 그림
+    #[cfg(feature = "double-buffer")]
+    let back_buffer = board_back_buffer(&front);
+    #[cfg(feature = "double-buffer")]
+    let context = back_buffer
+        .as_ref()
+        .map_or_else(|| front.clone(), |back_buffer| back_buffer.context.clone());
+    #[cfg(not(feature = "double-buffer"))]
+    let context = front;
+
+    // 게임 도중 캔버스 크기가 바뀔 수 있으므로 매 프레임 실제 캔버스 크기 기준으로 다시 계산
+    let metrics = GridMetrics::new(width, height, column_count, visible_row_count);
+
+    let key = BoardRenderKey {
+        metrics,
+        block_style,
+        border: border.clone(),
+        show_grid,
+        palette: palette.clone(),
+        pixel_ratio: window().device_pixel_ratio(),
+    };
+
+    // 크기나 스타일이 그대로라면 바뀐 칸만 다시 그림 (10x20 보드에서 미노가 한칸 움직이면 200칸 대신 8칸 이하)
+    // 첫 프레임, 캔버스 크기/해상도 변경, 보드 위에 다른 것을 덧그린 뒤에는 전체를 다시 그림
+    BOARD_CACHE.with(|cache| {
+        let mut cache = cache.borrow_mut();
+
+        match cache.as_ref() {
+            Some(previous)
+                if previous.key == key && previous.cells.len() == board_unfolded.len() =>
+            {
+                draw_changed_cells(
+                    &context,
+                    &tetris_board,
+                    &previous.cells,
+                    &metrics,
+                    block_style,
+                    show_grid,
+                    palette,
+                );
+
+                // 지난 프레임에 고스트를 덧그린 칸은 보드 칸으로 되돌림
+                for &(x, y) in &previous.ghost_cells {
+                    let cell = tetris_board.cells[y + hidden_row_count as usize][x];
+                    draw_board_cell(
+                        &context,
+                        &metrics,
+                        x,
+                        y,
+                        cell,
+                        block_style,
+                        show_grid,
+                        palette,
+                    );
+                }
+            }
+            _ => {
+                draw_board(
+                    &context,
+                    &tetris_board,
+                    &metrics,
+                    block_style,
+                    border,
+                    show_grid,
+                    palette,
+                );
+            }
+        }
+
+        let ghost_cells = match ghost {
+            Some(ghost) => draw_ghost(
+                &context,
+                &metrics,
+                &ghost,
+                column_count,
+                visible_row_count,
+                hidden_row_count,
+                block_style,
+                palette,
+            ),
+            None => vec![],
+        };
+
+        *cache = Some(BoardCache {
+            key,
+            cells: board_unfolded,
+            ghost_cells,
+        });
+    });
+
+    #[cfg(feature = "double-buffer")]
+    if let Some(back_buffer) = back_buffer {
+        back_buffer.present(&front)?;
+    }
+
+    Ok(())
+}
 
-    let document = web_sys::window().unwrap().document().unwrap();
-    let canvas = document.get_element_by_id("game-canvas").unwrap();
-    let canvas: web_sys::HtmlCanvasElement = canvas
-        .dyn_into::<web_sys::HtmlCanvasElement>()
-        .map_err(|_| ())
-        .unwrap();
+// 보드용 화면 밖 캔버스. 새로 만들었거나 크기가 바뀌어 지워졌다면 다음 그리기는 전체를 다시 그림
+#[cfg(feature = "double-buffer")]
+fn board_back_buffer(front: &CanvasRenderingContext2d) -> Option<BackBuffer> {
+    let (back_buffer, cleared) = BackBuffer::for_canvas(front)?;
+
+    if cleared {
+        invalidate_board_cache();
+    }
+
+    Some(back_buffer)
+}
+
+// 마지막으로 게임 캔버스에 그린 보드와 그때의 설정
+struct BoardCache {
+    key: BoardRenderKey,
+    cells: Vec<i32>,
+    ghost_cells: Vec<(usize, usize)>, // 보드 위에 고스트를 덧그린 칸 (보이는 영역 기준)
+}
+
+// 이 값이 하나라도 바뀌면 바뀐 칸만 그릴 수 없으므로 전체를 다시 그림
+#[derive(PartialEq)]
+struct BoardRenderKey {
+    metrics: GridMetrics,
+    block_style: BlockStyle,
+    border: PanelBorder,
+    show_grid: bool,
+    palette: CellPalette,
+    pixel_ratio: f64,
+}
+
+thread_local! {
+    static BOARD_CACHE: RefCell<Option<BoardCache>> = const { RefCell::new(None) };
+}
+
+// 보드 위에 고스트나 안내 화면을 덧그렸다면 다음 프레임은 전체를 다시 그리도록 캐시를 비움
+fn invalidate_board_cache() {
+    BOARD_CACHE.with(|cache| *cache.borrow_mut() = None);
+}
+
+// 보드를 PNG 이미지로 내보냄. 화면에 붙지 않은 캔버스에 그린 뒤 data URL로 반환
+// JS에는 현재 게임을 읽는 인자 없는 바인딩(manager의 export_board_png)으로 노출됨
+pub fn render_board_png(
+    tetris_board: &TetrisBoard,
+    block_style: BlockStyle,
+    border: &PanelBorder,
+    show_grid: bool,
+    palette: &CellPalette,
+) -> Result<String, JsValue> {
+    let visible_row_count = tetris_board
+        .row_count
+        .checked_sub(tetris_board.hidden_row_count)
+        .ok_or_else(|| JsValue::from_str("hidden_row_count is larger than row_count"))?;
+
+    let canvas = document()
+        .create_element("canvas")?
+        .dyn_into::<web_sys::HtmlCanvasElement>()?;
+    canvas.set_width(tetris_board.board_width);
+    canvas.set_height(tetris_board.board_height);
 
     let context = canvas
-        .get_context("2d")
-        .unwrap()
-        .unwrap()
-        .dyn_into::<web_sys::CanvasRenderingContext2d>()
-        .unwrap();
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("2d context is not supported"))?
+        .dyn_into::<web_sys::CanvasRenderingContext2d>()?;
+
+    let metrics = GridMetrics::new(
+        tetris_board.board_width,
+        tetris_board.board_height,
+        tetris_board.column_count,
+        visible_row_count,
+    );
+
+    draw_board(
+        &context,
+        tetris_board,
+        &metrics,
+        block_style,
+        border,
+        show_grid,
+        palette,
+    );
+
+    canvas.to_data_url_with_type("image/png")
+}
+
+// 주어진 컨텍스트에 보드 배경과 보이는 행의 블럭을 그림
+// 격자를 표시하면 빈 칸은 배경으로 두고 격자선 위에 쌓인 블럭만 그림
+fn draw_board(
+    context: &web_sys::CanvasRenderingContext2d,
+    tetris_board: &TetrisBoard,
+    metrics: &GridMetrics,
+    block_style: BlockStyle,
+    border: &PanelBorder,
+    show_grid: bool,
+    palette: &CellPalette,
+) {
+    let column_count = tetris_board.column_count;
+    let hidden_row_count = tetris_board.hidden_row_count;
+    let visible_row_count = tetris_board.row_count - hidden_row_count;
 
     context.begin_path();
 
-    context.set_fill_style(&JsValue::from_str(BOARD_DEFAULT_COLOR));
-    context.fill_rect(0.0, 0.0, board_width as f64, board_height as f64);
-    context.set_stroke_style(&JsValue::from_str(BOARD_STROKE_DEFAULT_COLOR));
-    context.stroke_rect(0.0, 0.0, board_width as f64, board_height as f64);
+    context.set_fill_style_str(BOARD_DEFAULT_COLOR);
+    context.fill_rect(0.0, 0.0, metrics.width, metrics.height);
+    context.set_stroke_style_str(&border.color);
+    context.set_line_width(border.width);
+    context.stroke_rect(0.0, 0.0, metrics.width, metrics.height);
+
+    if show_grid {
+        draw_grid(context, metrics, column_count, visible_row_count);
+    }
+
+    // 셀마다 색상을 바꾸지 않도록 모아서 색상별로 한번에 그림
+    let mut blocks = vec![];
 
     for x in 0..column_count {
         let x = x as usize;
@@ -71,231 +297,431 @@ pub fn render_board(
 
             let hidden_row_count = hidden_row_count as usize;
 
-            if tetris_board.cells[y + hidden_row_count][x] != TetrisCell::Empty {
-                let cell = tetris_board.cells[y + hidden_row_count][x];
-
-                let x = x as f64 * block_width_size;
-                let y = y as f64 * block_height_size;
-                draw_block(
-                    context.clone(),
-                    x,
-                    y,
-                    block_width_size,
-                    block_height_size,
-                    cell.to_color(),
-                );
-            } else {
-                let x = x as f64 * block_width_size;
-                let y = y as f64 * block_height_size;
-                draw_block(
-                    context.clone(),
-                    x,
-                    y,
-                    block_width_size,
-                    block_height_size,
-                    BOARD_DEFAULT_COLOR,
-                );
+            let cell = tetris_board.cells[y + hidden_row_count][x];
+            if show_grid && cell == TetrisCell::Empty {
+                continue;
             }
+
+            let color = if cell != TetrisCell::Empty {
+                palette.color(cell)
+            } else {
+                BOARD_DEFAULT_COLOR
+            };
+
+            let (x, y) = metrics.block_position(x as f64, y as f64);
+            blocks.push(BlockDraw {
+                x,
+                y,
+                color,
+                glyph: cell.glyph(),
+            });
         }
     }
+
+    draw_blocks_batched(
+        context,
+        &blocks,
+        metrics.block_width,
+        metrics.block_height,
+        block_style,
+    );
 }
 
+// 이전에 그린 보드와 비교해서 바뀐 칸만 다시 그림. previous_cells는 이전 보드의 unfold 결과
+#[allow(clippy::too_many_arguments)]
+fn draw_changed_cells(
+    context: &web_sys::CanvasRenderingContext2d,
+    tetris_board: &TetrisBoard,
+    previous_cells: &[i32],
+    metrics: &GridMetrics,
+    block_style: BlockStyle,
+    show_grid: bool,
+    palette: &CellPalette,
+) {
+    for (x, y, cell) in tetris_board.changed_visible_cells(previous_cells) {
+        draw_board_cell(
+            context,
+            metrics,
+            x,
+            y,
+            cell,
+            block_style,
+            show_grid,
+            palette,
+        );
+    }
+}
+
+// 보이는 영역 기준 (x, y) 칸 하나를 그림. 격자 모드의 빈 칸은 배경과 격자선으로 되돌림
+#[allow(clippy::too_many_arguments)]
+fn draw_board_cell(
+    context: &web_sys::CanvasRenderingContext2d,
+    metrics: &GridMetrics,
+    x: usize,
+    y: usize,
+    cell: TetrisCell,
+    block_style: BlockStyle,
+    show_grid: bool,
+    palette: &CellPalette,
+) {
+    let (x, y) = metrics.block_position(x as f64, y as f64);
+
+    if show_grid && cell == TetrisCell::Empty {
+        context.set_fill_style_str(BOARD_DEFAULT_COLOR);
+        context.fill_rect(x, y, metrics.block_width, metrics.block_height);
+        context.set_stroke_style_str(BOARD_GRID_DEFAULT_COLOR);
+        context.set_line_width(0.5);
+        context.stroke_rect(x, y, metrics.block_width, metrics.block_height);
+        return;
+    }
+
+    let color = if cell != TetrisCell::Empty {
+        palette.color(cell)
+    } else {
+        BOARD_DEFAULT_COLOR
+    };
+
+    draw_block(
+        context.clone(),
+        x,
+        y,
+        metrics.block_width,
+        metrics.block_height,
+        color,
+        cell.glyph(),
+        block_style,
+    );
+}
+
+// 칸 사이에 얇은 격자선을 그림
+fn draw_grid(
+    context: &web_sys::CanvasRenderingContext2d,
+    metrics: &GridMetrics,
+    column_count: u32,
+    row_count: u32,
+) {
+    // 맨 오른쪽/아래 선은 캔버스 밖으로 보정되지 않도록 block_position 대신 직접 계산
+    let line_x = |x: u32| (metrics.offset_x + x as f64 * metrics.block_width).round();
+    let line_y = |y: u32| (metrics.offset_y + y as f64 * metrics.block_height).round();
+
+    context.begin_path();
+    context.set_stroke_style_str(BOARD_GRID_DEFAULT_COLOR);
+    context.set_line_width(0.5);
+
+    for x in 0..=column_count {
+        context.move_to(line_x(x), line_y(0));
+        context.line_to(line_x(x), line_y(row_count));
+    }
+
+    for y in 0..=row_count {
+        context.move_to(line_x(0), line_y(y));
+        context.line_to(line_x(column_count), line_y(y));
+    }
+
+    context.stroke();
+}
+
+// 보드를 그린 뒤 반투명하게 덧그릴 고스트. points는 보드 좌표 (x, y) 쌍을 이어붙인 목록
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Debug, Clone)]
+pub struct GhostDraw {
+    pub points: Vec<i32>,
+    pub color_code: i32, // 현재 미노 색상
+    pub alpha: f64,      // 0.0 ~ 1.0
+}
+
+// 보드를 그린 컨텍스트 위에 고스트를 반투명하게 덧그리고, 덧그린 칸(보이는 영역 기준) 목록을 반환
+// 화면 밖 버퍼에 보드와 함께 그려지므로 다음 프레임에 이 칸들만 보드 칸으로 되돌리면 됨
+#[allow(clippy::too_many_arguments)]
+fn draw_ghost(
+    context: &CanvasRenderingContext2d,
+    metrics: &GridMetrics,
+    ghost: &GhostDraw,
+    column_count: u32,
+    visible_row_count: u32,
+    hidden_row_count: u32,
+    block_style: BlockStyle,
+    palette: &CellPalette,
+) -> Vec<(usize, usize)> {
+    let color = match TetrisCell::try_from(ghost.color_code) {
+        Ok(cell) => cell,
+        Err(error) => {
+            log::warn!("ghost skipped: {}", error);
+            return vec![];
+        }
+    };
+
+    let mut ghost_cells = vec![];
+
+    context.set_global_alpha(ghost.alpha.clamp(0.0, 1.0));
+
+    for point in ghost.points.chunks_exact(2) {
+        let (x, y) = (point[0], point[1] - hidden_row_count as i32);
+
+        // 숨겨진 행에 걸친 칸이나 보드 밖의 칸은 그리지 않음
+        if x < 0 || y < 0 || x as u32 >= column_count || y as u32 >= visible_row_count {
+            continue;
+        }
+
+        ghost_cells.push((x as usize, y as usize));
+
+        let (x, y) = metrics.block_position(x as f64, y as f64);
+        draw_block(
+            context.clone(),
+            x,
+            y,
+            metrics.block_width,
+            metrics.block_height,
+            palette.color(color),
+            color.glyph(),
+            block_style,
+        );
+    }
+
+    context.set_global_alpha(1.0);
+
+    ghost_cells
+}
+
+// 게임 캔버스를 어둡게 덮고 그 위에 조작법 목록을 글자로 그림
 #[wasm_bindgen]
+pub fn render_help(lines: Vec<String>) -> Result<(), JsValue> {
+    invalidate_board_cache();
+
+    let (context, width, height) = canvas_context(GAME_CANVAS_ID)?;
+    let (width, height) = (width as f64, height as f64);
+
+    context.set_fill_style_str("rgba(0, 0, 0, 0.7)");
+    context.fill_rect(0.0, 0.0, width, height);
+
+    context.set_fill_style_str("white");
+    context.set_text_baseline("top");
+    context.set_font("bold 20px monospace");
+    context.fill_text("Controls", 20.0, 20.0)?;
+
+    context.set_font("16px monospace");
+    for (index, line) in lines.iter().enumerate() {
+        context.fill_text(line, 20.0, 60.0 + index as f64 * 24.0)?;
+    }
+
+    Ok(())
+}
+
+// 게임 캔버스를 어둡게 덮고 게임 오버 문구와 최종 점수, 재시작 안내를 가운데에 그림
+#[wasm_bindgen]
+pub fn render_game_over(score: String) -> Result<(), JsValue> {
+    invalidate_board_cache();
+
+    let (context, width, height) = canvas_context(GAME_CANVAS_ID)?;
+    let (width, height) = (width as f64, height as f64);
+
+    context.set_fill_style_str("rgba(0, 0, 0, 0.7)");
+    context.fill_rect(0.0, 0.0, width, height);
+
+    context.set_fill_style_str("white");
+    context.set_text_align("center");
+    context.set_text_baseline("middle");
+    context.set_font("bold 32px monospace");
+    context.fill_text("Game Over", width / 2.0, height / 2.0 - 40.0)?;
+
+    context.set_font("20px monospace");
+    context.fill_text(&format!("Score {}", score), width / 2.0, height / 2.0)?;
+
+    context.set_font("16px monospace");
+    context.fill_text(
+        "Press Start to play again",
+        width / 2.0,
+        height / 2.0 + 40.0,
+    )?;
+
+    // 다른 렌더링에 영향이 없도록 정렬은 기본값으로 되돌림
+    context.set_text_align("start");
+
+    Ok(())
+}
+
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
 pub fn render_next(
     mino_list: Vec<i32>,
     board_width: u32,
     board_height: u32,
     column_count: u8,
     row_count: u8,
-) {
-    let block_width_size = (board_width / column_count as u32) as f64;
-    let block_height_size = (board_height / row_count as u32) as f64;
-
+    block_style: BlockStyle,
+    border: &PanelBorder,
+    palette: &CellPalette,
+    mirror: bool,
+) -> Result<(), JsValue> {
     let mino_shapes = mino_list
         .into_iter()
-        .map(|e| e.into())
+        .map(|e| panel_shape(e, mirror))
         .collect::<Vec<MinoShape>>();
 
-    let document = web_sys::window().unwrap().document().unwrap();
-    let canvas = document.get_element_by_id("next-canvas").unwrap();
-    let canvas: web_sys::HtmlCanvasElement = canvas
-        .dyn_into::<web_sys::HtmlCanvasElement>()
-        .map_err(|_| ())
-        .unwrap();
+    let (context, _, _) = canvas_context(NEXT_CANVAS_ID)?;
 
-    let context = canvas
-        .get_context("2d")
-        .unwrap()
-        .unwrap()
-        .dyn_into::<web_sys::CanvasRenderingContext2d>()
-        .unwrap();
+    let metrics = GridMetrics::new(
+        board_width,
+        board_height,
+        column_count as u32,
+        row_count as u32,
+    );
 
     context.begin_path();
 
     // 검은색으로 세팅
-    context.set_fill_style(&JsValue::from_str(NEXT_DEFAULT_COLOR));
-    context.fill_rect(0.0, 0.0, board_width as f64, board_height as f64);
-    context.set_stroke_style(&JsValue::from_str(NEXT_STROKE_DEFAULT_COLOR));
-    context.stroke_rect(0.0, 0.0, board_width as f64, board_height as f64);
-
-    let mut mino_iter = mino_shapes.iter();
-    let mut current_mino = VecDeque::new();
-
-    for y in 0..row_count {
-        if current_mino.is_empty() {
-            match mino_iter.next() {
-                Some(mino) => {
-                    current_mino = mino.cells.iter().cloned().collect();
-                    continue;
-                }
-                None => {
-                    break;
-                }
-            }
+    context.set_fill_style_str(NEXT_DEFAULT_COLOR);
+    context.fill_rect(0.0, 0.0, metrics.width, metrics.height);
+    context.set_stroke_style_str(&border.color);
+    context.set_line_width(border.width);
+    context.stroke_rect(0.0, 0.0, metrics.width, metrics.height);
+
+    // 미노 하나당 위쪽 빈 줄 1칸과 가운데 4x4 영역을 차지
+    for (index, mino) in mino_shapes.iter().enumerate() {
+        let top = index as u32 * PANEL_SLOT_ROW_COUNT + 1;
+
+        if top + PANEL_SLOT_SIZE > row_count as u32 {
+            break;
         }
 
-        let current_mino_row = current_mino.pop_front().unwrap();
-
-        let y = y as usize;
-
-        for x in 1..(column_count - 1) {
-            let x = x as usize;
-
-            let cell = current_mino_row.get(x - 1);
-
-            if cell != Some(&TetrisCell::Empty) && cell.is_some() {
-                let cell = current_mino_row[x - 1];
-
-                let x = x as f64 * block_width_size;
-                let y = y as f64 * block_height_size;
-                draw_block(
-                    context.clone(),
-                    x,
-                    y,
-                    block_width_size,
-                    block_height_size,
-                    cell.to_color(),
-                );
-            } else {
-                let x = x as f64 * block_width_size;
-                let y = y as f64 * block_height_size;
-                draw_block(
-                    context.clone(),
-                    x,
-                    y,
-                    block_width_size,
-                    block_height_size,
-                    NEXT_DEFAULT_COLOR,
-                );
-            }
-        }
+        draw_centered_mino(&context, &metrics, mino, 1, top, block_style, palette);
     }
+
+    Ok(())
 }
 
 #[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
 pub fn render_hold(
     mino: Option<i32>,
     board_width: u32,
     board_height: u32,
     column_count: u8,
     row_count: u8,
-) {
-    let block_width_size = (board_width / column_count as u32) as f64;
-    let block_height_size = (board_height / row_count as u32) as f64;
-
+    block_style: BlockStyle,
+    border: &PanelBorder,
+    palette: &CellPalette,
+    mirror: bool,
+) -> Result<(), JsValue> {
     let mino_shapes = match mino {
         Some(mino) => [mino]
             .into_iter()
-            .map(|e| e.into())
+            .map(|e| panel_shape(e, mirror))
             .collect::<Vec<MinoShape>>(),
         None => vec![],
     };
 
-    let document = web_sys::window().unwrap().document().unwrap();
-    let canvas = document.get_element_by_id("hold-canvas").unwrap();
-    let canvas: web_sys::HtmlCanvasElement = canvas
-        .dyn_into::<web_sys::HtmlCanvasElement>()
-        .map_err(|_| ())
-        .unwrap();
+    let (context, _, _) = canvas_context(HOLD_CANVAS_ID)?;
 
-    let context = canvas
-        .get_context("2d")
-        .unwrap()
-        .unwrap()
-        .dyn_into::<web_sys::CanvasRenderingContext2d>()
-        .unwrap();
+    let metrics = GridMetrics::new(
+        board_width,
+        board_height,
+        column_count as u32,
+        row_count as u32,
+    );
 
     context.begin_path();
 
     // 검은색으로 세팅
-    context.set_fill_style(&JsValue::from_str(HOLD_DEFAULT_COLOR));
-    context.fill_rect(0.0, 0.0, board_width as f64, board_height as f64);
-    context.set_stroke_style(&JsValue::from_str(HOLD_STROKE_DEFAULT_COLOR));
-    context.stroke_rect(0.0, 0.0, board_width as f64, board_height as f64);
-
-    let mut mino_iter = mino_shapes.iter();
-    let mut current_mino = VecDeque::new();
-
-    for y in 0..row_count {
-        if current_mino.is_empty() {
-            match mino_iter.next() {
-                Some(mino) => {
-                    current_mino = mino.cells.iter().cloned().collect();
-                    continue;
-                }
-                None => {
-                    break;
-                }
-            }
-        }
-
-        let current_mino_row = current_mino.pop_front().unwrap();
+    context.set_fill_style_str(HOLD_DEFAULT_COLOR);
+    context.fill_rect(0.0, 0.0, metrics.width, metrics.height);
+    context.set_stroke_style_str(&border.color);
+    context.set_line_width(border.width);
+    context.stroke_rect(0.0, 0.0, metrics.width, metrics.height);
+
+    if let Some(mino) = mino_shapes.first() {
+        draw_centered_mino(&context, &metrics, mino, 1, 1, block_style, palette);
+    }
 
-        let y = y as usize;
+    Ok(())
+}
 
-        for x in 1..(column_count - 1) {
-            let x = x as usize;
+// 넥스트/홀드 패널에서 미노 하나가 차지하는 영역의 한 변 칸 수와, 위쪽 빈 줄을 포함한 행 수
+const PANEL_SLOT_SIZE: u32 = 4;
+const PANEL_SLOT_ROW_COUNT: u32 = PANEL_SLOT_SIZE + 1;
 
-            let cell = current_mino_row.get(x - 1);
+// 패널에 그릴 미노 모양. 거울 모드라면 보드와 같이 좌우를 뒤집음
+fn panel_shape(code: i32, mirror: bool) -> MinoShape {
+    let shape = MinoShape::from(code);
 
-            if cell != Some(&TetrisCell::Empty) && cell.is_some() {
-                let cell = current_mino_row[x - 1];
+    if mirror {
+        shape.mirrored()
+    } else {
+        shape
+    }
+}
 
-                let x = x as f64 * block_width_size;
-                let y = y as f64 * block_height_size;
-                draw_block(
-                    context.clone(),
-                    x,
-                    y,
-                    block_width_size,
-                    block_height_size,
-                    cell.to_color(),
-                );
-            } else {
-                let x = x as f64 * block_width_size;
-                let y = y as f64 * block_height_size;
-                draw_block(
-                    context.clone(),
-                    x,
-                    y,
-                    block_width_size,
-                    block_height_size,
-                    HOLD_DEFAULT_COLOR,
-                );
-            }
-        }
+// 미노 모양에서 빈 행/열을 잘라내고 (left, top)에서 시작하는 4x4 칸 영역 가운데에 그림
+// I나 O처럼 모양 크기가 달라도 패널 안에서 같은 위치에 보이도록 반 칸 단위로 맞춤
+fn draw_centered_mino(
+    context: &CanvasRenderingContext2d,
+    metrics: &GridMetrics,
+    mino: &MinoShape,
+    left: u32,
+    top: u32,
+    block_style: BlockStyle,
+    palette: &CellPalette,
+) {
+    let filled = mino
+        .cells
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, cell)| (x, y, *cell)))
+        .filter(|(_, _, cell)| !cell.is_empty())
+        .collect::<Vec<_>>();
+
+    let (min_x, max_x) = match (
+        filled.iter().map(|(x, _, _)| *x).min(),
+        filled.iter().map(|(x, _, _)| *x).max(),
+    ) {
+        (Some(min_x), Some(max_x)) => (min_x as f64, max_x as f64),
+        _ => return,
+    };
+    let min_y = filled.iter().map(|(_, y, _)| *y).min().unwrap_or(0) as f64;
+    let max_y = filled.iter().map(|(_, y, _)| *y).max().unwrap_or(0) as f64;
+
+    let slot_size = PANEL_SLOT_SIZE as f64;
+    let offset_x = left as f64 + (slot_size - (max_x - min_x + 1.0)) / 2.0 - min_x;
+    let offset_y = top as f64 + (slot_size - (max_y - min_y + 1.0)) / 2.0 - min_y;
+
+    for (x, y, cell) in filled {
+        let (x, y) = metrics.block_position(offset_x + x as f64, offset_y + y as f64);
+        draw_block(
+            context.clone(),
+            x,
+            y,
+            metrics.block_width,
+            metrics.block_height,
+            palette.color(cell),
+            cell.glyph(),
+            block_style,
+        );
     }
 }
 
-#[wasm_bindgen]
-pub fn run_render() -> Result<(), JsValue> {
-    let f = Rc::new(RefCell::new(None));
+// 애니메이션 프레임마다 snapshot으로 현재 게임 상태를 복사한 뒤 잠금을 풀고 render_frame으로 그림
+// 그리는 동안에는 잠금을 잡지 않으므로 틱 루프나 입력 처리가 그리기를 기다리지 않음
+// stop이 true를 반환하면 더 이상 프레임을 요청하지 않고 클로저 핸들을 놓아서 정리되도록 함
+pub fn run_render<V: 'static>(
+    game_info: Arc<Mutex<GameInfo>>,
+    stop: impl Fn(&GameInfo) -> bool + 'static,
+    snapshot: impl Fn(&mut GameInfo) -> V + 'static,
+    render_frame: impl Fn(V) + 'static,
+) {
+    let f = Rc::new(RefCell::new(None::<Closure<dyn FnMut()>>));
     let g = f.clone();
 
-    let mut i = 0;
+    // 마지막으로 그린 시점. render_interval보다 짧은 간격으로 돌아온 프레임은 건너뜀
+    let mut last_render: Option<instant::Instant> = None;
+
     *g.borrow_mut() = Some(Closure::new(move || {
-        if i > 300 {
-            body().set_text_content(Some("All done!"));
+        let mut game_info = lock_or_recover(&game_info);
+
+        if stop(&game_info) {
+            // 게임 오버처럼 멈추기 직전에 바뀐 상태가 화면에 남도록 마지막으로 한번 더 그림
+            let view = snapshot(&mut game_info);
+            drop(game_info);
+            render_frame(view);
 
             // Drop our handle to this closure so that it will get cleaned
             // up once we return.
@@ -303,16 +729,24 @@ pub fn run_render() -> Result<(), JsValue> {
             return;
         }
 
-        // Set the body's text content to how many times this
-        // requestAnimationFrame callback has fired.
-        i += 1;
-        let text = format!("requestAnimationFrame has been called {} times.", i);
-        body().set_text_content(Some(&text));
+        // 간격은 매 프레임 다시 읽으므로 게임 도중 바꿔도 바로 반영됨
+        let now = instant::Instant::now();
+        let due = last_render.is_none_or(|last_render| {
+            now.duration_since(last_render).as_millis() as u64 >= game_info.render_interval
+        });
+
+        if due {
+            last_render = Some(now);
+            let view = snapshot(&mut game_info);
+            drop(game_info);
+            render_frame(view);
+        } else {
+            drop(game_info);
+        }
 
         // Schedule ourself for another requestAnimationFrame callback.
         request_animation_frame(f.borrow().as_ref().unwrap());
     }));
 
     request_animation_frame(g.borrow().as_ref().unwrap());
-    Ok(())
 }